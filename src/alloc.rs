@@ -0,0 +1,138 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fmt::{self, Display};
+use std::sync::atomic::{AtomicBool, AtomicIsize, AtomicUsize, Ordering};
+
+use serde::Serialize;
+
+/// The system allocator, counting what's allocated once [`enable`] is called.
+///
+/// The counts are global, so a measurement is only right when nothing else allocates at the same
+/// time, like other days running in parallel.
+pub struct CountingAllocator;
+
+static COUNTING: AtomicBool = AtomicBool::new(false);
+/// Bytes currently allocated, relative to when counting started. Memory allocated before that
+/// can be freed after, so it can go below 0.
+static CURRENT: AtomicIsize = AtomicIsize::new(0);
+static PEAK: AtomicIsize = AtomicIsize::new(0);
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+
+fn count_alloc(size: usize) {
+    let current = CURRENT.fetch_add(size as isize, Ordering::Relaxed) + size as isize;
+    PEAK.fetch_max(current, Ordering::Relaxed);
+    ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    ALLOCATED.fetch_add(size, Ordering::Relaxed);
+}
+
+fn count_dealloc(size: usize) {
+    CURRENT.fetch_sub(size as isize, Ordering::Relaxed);
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() && COUNTING.load(Ordering::Relaxed) {
+            count_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() && COUNTING.load(Ordering::Relaxed) {
+            count_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        if COUNTING.load(Ordering::Relaxed) {
+            count_dealloc(layout.size());
+        }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() && COUNTING.load(Ordering::Relaxed) {
+            // Counted as a new allocation, which it is unless it grew in place.
+            count_dealloc(layout.size());
+            count_alloc(new_size);
+        }
+        new_ptr
+    }
+}
+
+/// Starts counting allocations, until then everything is free.
+pub fn enable() {
+    COUNTING.store(true, Ordering::Relaxed);
+}
+
+/// What a phase of a day allocated.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct MemStats {
+    /// The most the heap grew over what it was when the phase started.
+    pub peak_bytes: usize,
+    pub allocations: usize,
+    /// Every allocation added up, even those freed right away.
+    pub allocated_bytes: usize,
+}
+
+/// Counts what is allocated from its start to [`Measurement::finish`].
+pub struct Measurement {
+    current: isize,
+    allocations: usize,
+    allocated: usize,
+}
+
+impl Measurement {
+    pub fn start() -> Self {
+        let current = CURRENT.load(Ordering::Relaxed);
+        PEAK.store(current, Ordering::Relaxed);
+        Self {
+            current,
+            allocations: ALLOCATIONS.load(Ordering::Relaxed),
+            allocated: ALLOCATED.load(Ordering::Relaxed),
+        }
+    }
+
+    pub fn finish(self) -> MemStats {
+        MemStats {
+            peak_bytes: (PEAK.load(Ordering::Relaxed) - self.current).max(0) as usize,
+            allocations: ALLOCATIONS.load(Ordering::Relaxed) - self.allocations,
+            allocated_bytes: ALLOCATED.load(Ordering::Relaxed) - self.allocated,
+        }
+    }
+}
+
+/// Formats `bytes` in the largest binary unit it's at least 1 of.
+pub fn bytes_to_string(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = UNITS[0];
+    for next in &UNITS[1..] {
+        if size < 1024.0 {
+            break;
+        }
+        size /= 1024.0;
+        unit = next;
+    }
+    format!("{:.2} {}", size, unit)
+}
+
+impl Display for MemStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} peak, {} allocation{} ({} total)",
+            bytes_to_string(self.peak_bytes),
+            self.allocations,
+            if self.allocations == 1 { "" } else { "s" },
+            bytes_to_string(self.allocated_bytes)
+        )
+    }
+}
@@ -0,0 +1,78 @@
+use std::{fs, io};
+
+use serde::{Deserialize, Serialize};
+
+use crate::cli_error::Error;
+
+/// Answers adventofcode.com accepted for a day, kept in `answers/YEAR/dayN.toml`.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Answers {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+fn answers_dir(year: u16) -> String {
+    format!("answers/{}", year)
+}
+
+fn answers_file_path(year: u16, day: u8) -> String {
+    format!("{}/day{}.toml", answers_dir(year), day)
+}
+
+impl Answers {
+    /// Loads the stored answers of `day` of `year`, there are none if the file doesn't exist yet.
+    pub fn load(year: u16, day: u8) -> Result<Self, Error> {
+        let path = answers_file_path(year, day);
+        match fs::read_to_string(&path) {
+            Ok(contents) => toml::from_str(&contents).map_err(|err| {
+                Error::io(
+                    format!("Could not parse answers file ({})", path),
+                    io::Error::new(io::ErrorKind::InvalidData, err),
+                )
+            }),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(Error::io(
+                format!("Could not read answers file ({})", path),
+                err,
+            )),
+        }
+    }
+
+    pub fn save(&self, year: u16, day: u8) -> Result<(), Error> {
+        let path = answers_file_path(year, day);
+        let contents = toml::to_string(self).map_err(|err| {
+            Error::io(
+                format!("Could not serialize answers file ({})", path),
+                io::Error::new(io::ErrorKind::InvalidData, err),
+            )
+        })?;
+        fs::create_dir_all(answers_dir(year))
+            .and_then(|_| fs::write(&path, contents))
+            .map_err(|err| Error::io(format!("Could not write answers file ({})", path), err))
+    }
+
+    pub fn get(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part1.as_deref(),
+            _ => self.part2.as_deref(),
+        }
+    }
+
+    fn set(&mut self, part: u8, answer: &str) {
+        let slot = match part {
+            1 => &mut self.part1,
+            _ => &mut self.part2,
+        };
+        *slot = Some(answer.to_string());
+    }
+}
+
+/// Stores `answer` as the known good answer to `part` of `day` of `year`.
+pub fn remember(year: u16, day: u8, part: u8, answer: &str) -> Result<(), Error> {
+    let mut answers = Answers::load(year, day)?;
+    if answers.get(part) == Some(answer) {
+        return Ok(());
+    }
+    answers.set(part, answer);
+    answers.save(year, day)
+}
@@ -0,0 +1,226 @@
+use std::collections::BTreeMap;
+use std::time::Instant;
+use std::{fs, io};
+
+use advent_of_code::days::{parse_input, solve_part, with_day, Day, DayVisitor};
+use colored::*;
+use serde::{Deserialize, Serialize};
+
+use crate::cli_error::Error;
+use crate::runner::{change_to_string, nanos_to_string, read_cached_input};
+
+const BASELINE_FILE_PATH: &str = "benchmarks/baseline.toml";
+
+/// Summary of the times a phase took over all iterations.
+#[derive(Debug, Clone, Copy)]
+pub struct Stats {
+    pub mean: u128,
+    pub median: u128,
+    pub stddev: u128,
+    pub min: u128,
+    pub max: u128,
+}
+
+impl Stats {
+    fn from_samples(samples: &mut [u128]) -> Self {
+        samples.sort_unstable();
+        let len = samples.len() as u128;
+        let mean = samples.iter().sum::<u128>() / len;
+        let median = if samples.len().is_multiple_of(2) {
+            (samples[samples.len() / 2 - 1] + samples[samples.len() / 2]) / 2
+        } else {
+            samples[samples.len() / 2]
+        };
+        let variance = samples
+            .iter()
+            .map(|&sample| (sample as f64 - mean as f64).powi(2))
+            .sum::<f64>()
+            / len as f64;
+        Self {
+            mean,
+            median,
+            stddev: variance.sqrt() as u128,
+            min: samples[0],
+            max: samples[samples.len() - 1],
+        }
+    }
+}
+
+/// The mean times of a day's phases, what later benchmarks are compared against.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct DayBaseline {
+    pub parsing: u64,
+    pub part1: u64,
+    pub part2: u64,
+}
+
+/// The baselines of every benchmarked day, kept in `benchmarks/baseline.toml` under a table per
+/// year.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Baseline {
+    #[serde(flatten)]
+    years: BTreeMap<String, BTreeMap<String, DayBaseline>>,
+}
+
+impl Baseline {
+    /// Loads the baseline, it's empty if nothing was benchmarked yet.
+    pub fn load() -> Result<Self, Error> {
+        match fs::read_to_string(BASELINE_FILE_PATH) {
+            Ok(contents) => toml::from_str(&contents).map_err(|err| {
+                Error::io(
+                    format!("Could not parse baseline file ({})", BASELINE_FILE_PATH),
+                    io::Error::new(io::ErrorKind::InvalidData, err),
+                )
+            }),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(Error::io(
+                format!("Could not read baseline file ({})", BASELINE_FILE_PATH),
+                err,
+            )),
+        }
+    }
+
+    pub fn save(&self) -> Result<(), Error> {
+        let contents = toml::to_string(self).map_err(|err| {
+            Error::io(
+                format!("Could not serialize baseline file ({})", BASELINE_FILE_PATH),
+                io::Error::new(io::ErrorKind::InvalidData, err),
+            )
+        })?;
+        fs::create_dir_all("benchmarks")
+            .and_then(|_| fs::write(BASELINE_FILE_PATH, contents))
+            .map_err(|err| {
+                Error::io(
+                    format!("Could not write baseline file ({})", BASELINE_FILE_PATH),
+                    err,
+                )
+            })
+    }
+
+    pub fn get(&self, year: u16, day: u8) -> Option<DayBaseline> {
+        self.years
+            .get(&year.to_string())
+            .and_then(|days| days.get(&format!("day{}", day)))
+            .copied()
+    }
+
+    pub fn set(&mut self, year: u16, day: u8, baseline: DayBaseline) {
+        self.years
+            .entry(year.to_string())
+            .or_default()
+            .insert(format!("day{}", day), baseline);
+    }
+}
+
+/// Stats of every phase of a benchmarked day.
+#[derive(Debug, Clone, Copy)]
+pub struct DayStats {
+    pub parsing: Stats,
+    pub part1: Stats,
+    pub part2: Stats,
+}
+
+impl DayStats {
+    pub fn baseline(&self) -> DayBaseline {
+        DayBaseline {
+            parsing: self.parsing.mean as u64,
+            part1: self.part1.mean as u64,
+            part2: self.part2.mean as u64,
+        }
+    }
+}
+
+/// Times every phase of a day once.
+fn run_once<D: Day>(day_num: u8, input: &str) -> Result<(u128, u128, u128), Error> {
+    let start_parsing = Instant::now();
+    let parsed_input = parse_input::<D>(day_num, input)?;
+    let elapsed_parsing = start_parsing.elapsed().as_nanos();
+
+    let start_part1 = Instant::now();
+    solve_part::<D>(day_num, 1, &parsed_input)?;
+    let elapsed_part1 = start_part1.elapsed().as_nanos();
+
+    let start_part2 = Instant::now();
+    solve_part::<D>(day_num, 2, &parsed_input)?;
+    let elapsed_part2 = start_part2.elapsed().as_nanos();
+
+    Ok((elapsed_parsing, elapsed_part1, elapsed_part2))
+}
+
+fn print_stats(name: &str, stats: Stats, baseline: Option<u64>) {
+    let baseline = baseline.map(u128::from);
+    let change = match baseline {
+        Some(baseline) => format!(" ({})", change_to_string(stats.mean, baseline)),
+        None => String::new(),
+    };
+    println!(
+        "{} {} ± {}, median {}, min {}, max {}{}",
+        name.bold(),
+        nanos_to_string(stats.mean, baseline),
+        nanos_to_string(stats.stddev, None),
+        nanos_to_string(stats.median, None),
+        nanos_to_string(stats.min, None),
+        nanos_to_string(stats.max, None),
+        change
+    );
+}
+
+struct Bencher {
+    year: u16,
+    warmup: usize,
+    iterations: usize,
+    baseline: Option<DayBaseline>,
+}
+
+impl DayVisitor for Bencher {
+    type Output = Result<DayStats, Error>;
+
+    fn visit<D: Day>(self, day_num: u8) -> Self::Output {
+        let input = read_cached_input(self.year, day_num)?;
+
+        println!("{}", format!("Day {}", day_num).bold().green());
+        for _ in 0..self.warmup {
+            run_once::<D>(day_num, &input)?;
+        }
+
+        let mut parsing = Vec::with_capacity(self.iterations);
+        let mut part1 = Vec::with_capacity(self.iterations);
+        let mut part2 = Vec::with_capacity(self.iterations);
+        for _ in 0..self.iterations {
+            let times = run_once::<D>(day_num, &input)?;
+            parsing.push(times.0);
+            part1.push(times.1);
+            part2.push(times.2);
+        }
+
+        let stats = DayStats {
+            parsing: Stats::from_samples(&mut parsing),
+            part1: Stats::from_samples(&mut part1),
+            part2: Stats::from_samples(&mut part2),
+        };
+        print_stats("Parsing:", stats.parsing, self.baseline.map(|b| b.parsing));
+        print_stats("Part 1:", stats.part1, self.baseline.map(|b| b.part1));
+        print_stats("Part 2:", stats.part2, self.baseline.map(|b| b.part2));
+        Ok(stats)
+    }
+}
+
+/// Benchmarks `day_num` of `year` over `iterations` runs after `warmup` unmeasured ones.
+pub fn bench_day(
+    year: u16,
+    day_num: u8,
+    warmup: usize,
+    iterations: usize,
+    baseline: Option<DayBaseline>,
+) -> Result<DayStats, Error> {
+    with_day(
+        year,
+        day_num,
+        Bencher {
+            year,
+            warmup,
+            iterations: iterations.max(1),
+            baseline,
+        },
+    )?
+}
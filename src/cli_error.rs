@@ -0,0 +1,108 @@
+use std::{
+    fmt::{Display, Formatter},
+    io,
+    time::Duration,
+};
+
+/// What can go wrong in the CLI, on top of the errors of the library's days.
+#[derive(Debug)]
+pub enum Error {
+    /// Picking, parsing or solving a day failed.
+    Day(advent_of_code::Error),
+    /// The input file couldn't be opened, read or written.
+    Io { context: String, source: io::Error },
+    /// The session cookie couldn't be loaded.
+    Session { context: String, source: io::Error },
+    /// The site didn't accept the session cookie, it's probably expired.
+    LoggedOut,
+    /// A request to adventofcode.com failed.
+    Network {
+        context: String,
+        source: reqwest::Error,
+    },
+    /// adventofcode.com answered, but not with what was asked for.
+    BadResponse { context: String, message: String },
+    /// The day panicked outside of the parts, like while parsing its input.
+    Panicked { day: u8, message: String },
+    /// The day didn't finish in time and was abandoned.
+    TimedOut { day: u8, limit: Duration },
+}
+
+impl Error {
+    /// The status the CLI exits with, distinct per kind of failure.
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Error::Day(err) => err.exit_code(),
+            Error::Io { .. } => 3,
+            Error::Session { .. } | Error::LoggedOut => 4,
+            Error::Network { .. } | Error::BadResponse { .. } => 5,
+            Error::Panicked { .. } => 7,
+            Error::TimedOut { .. } => 8,
+        }
+    }
+
+    /// The day that failed while parsing or solving its input, if that's what went wrong.
+    pub fn failed_day(&self) -> Option<u8> {
+        match self {
+            Error::Day(advent_of_code::Error::Parse { day, .. })
+            | Error::Day(advent_of_code::Error::Solver { day, .. })
+            | Error::Panicked { day, .. }
+            | Error::TimedOut { day, .. } => Some(*day),
+            _ => None,
+        }
+    }
+
+    pub fn io(context: impl Into<String>, source: io::Error) -> Self {
+        Error::Io {
+            context: context.into(),
+            source,
+        }
+    }
+
+    pub fn network(context: impl Into<String>, source: reqwest::Error) -> Self {
+        Error::Network {
+            context: context.into(),
+            source,
+        }
+    }
+}
+
+impl From<advent_of_code::Error> for Error {
+    fn from(err: advent_of_code::Error) -> Self {
+        Error::Day(err)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Day(err) => write!(f, "{}", err),
+            Error::Io { context, source } => write!(f, "{}: {}", context, source),
+            Error::Session { context, source } => write!(f, "{}: {}", context, source),
+            Error::LoggedOut => write!(
+                f,
+                "The session cookie was not accepted, log in again and update it"
+            ),
+            Error::Network { context, source } => write!(f, "{}: {}", context, source),
+            Error::BadResponse { context, message } => write!(f, "{}: {}", context, message),
+            Error::Panicked { day, message } => write!(f, "Day {} panicked: {}", day, message),
+            Error::TimedOut { day, limit } => {
+                write!(f, "Day {} timed out after {:?}", day, limit)
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Day(err) => err.source(),
+            Error::Io { source, .. } | Error::Session { source, .. } => Some(source),
+            Error::Network { source, .. } => Some(source),
+            Error::LoggedOut
+            | Error::BadResponse { .. }
+            | Error::Panicked { .. }
+            | Error::TimedOut { .. } => None,
+        }
+    }
+}
@@ -1,31 +1,20 @@
-use crate::days;
+use crate::days::{self, Answer};
+use crate::error::ParseError;
 
-pub struct Day {
-    day_num: u8,
-}
+pub struct Day;
 
 impl days::Day for Day {
     type Input = String;
 
-    fn get_num(&self) -> u8 {
-        self.day_num
-    }
-
-    fn new(day_num: u8) -> Self {
-        Self {
-            day_num
-        }
-    }
-
-    fn part1(&mut self, _input: &Self::Input) -> (String, bool) {
-        todo!("Part 1 Day {}", self.get_num())
+    fn part1(_input: &Self::Input) -> Answer {
+        todo!("Part 1")
     }
 
-    fn part2(&mut self, _input: &Self::Input) -> (String, bool) {
-        todo!("Part 2 Day {}", self.get_num())
+    fn part2(_input: &Self::Input) -> Answer {
+        todo!("Part 2")
     }
 
-    fn parse_input(&mut self, input: &String) -> Self::Input {
-        input.clone()
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.to_string())
     }
 }
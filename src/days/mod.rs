@@ -1,350 +1,215 @@
-use std::collections::HashMap;
-use std::io::Write;
-use std::{fs::File, io::Read, time::Instant};
+use std::any::Any;
+use std::fmt::{Display, Formatter};
+use std::panic::{self, AssertUnwindSafe};
 
-use colored::*;
 use paste::paste;
-use regex::Regex;
+
+use crate::error::{Error, ParseError};
 
 macro_rules! run_day {
-    ($($day:expr),+) => {
+    ($($year:literal => [$($day:literal),+ $(,)?]),+ $(,)?) => {
         $(
-            paste! { mod [<day $day>] ; }
+            paste! {
+                pub mod [<y $year>] {
+                    $(pub mod [<day $day>];)+
+                }
+            }
         )+
 
-        pub fn run_day(day_num: u8, time: bool, dont_print: bool, dontsubmit: bool, dontinput: bool, client: &reqwest::blocking::Client) -> Option<(u128, u128, u128)> {
-            if day_num < 1 || day_num > 25 {
-                println!("{}", "Day number must be between 1 and 25".bold().red());
-                return None;
+        /// The years with solutions, oldest first.
+        pub const YEARS: &[u16] = &[$($year),+];
+
+        /// Hands the solution of `day_num` of `year` to `visitor`.
+        pub fn with_day<V: DayVisitor>(year: u16, day_num: u8, visitor: V) -> Result<V::Output, Error> {
+            if !YEARS.contains(&year) {
+                return Err(Error::InvalidYear(year));
             }
-            return match day_num {
-                $(
-                    $day => run_impled_day(paste! { &mut [<day $day>]::Day::new(day_num) }, time, dont_print, dontsubmit, dontinput, client),
-                )+
-                _ => {
-                    println!("{}", format!("Day {} not implemented yet", day_num).bold().red());
-                    None
+            if !(1..=25).contains(&day_num) {
+                return Err(Error::InvalidDay(day_num.to_string()));
+            }
+            paste! {
+                match (year, day_num) {
+                    $($(
+                        ($year, $day) => Ok(visitor.visit::<[<y $year>]::[<day $day>]::Day>(day_num)),
+                    )+)+
+                    _ => Err(Error::NotImplemented(day_num)),
                 }
             }
         }
     }
 }
 
+/// The solution of a day. Holds no state, everything the parts share is in its parsed input, so
+/// either part can run on its own and as often as needed.
 pub trait Day {
     type Input;
 
-    fn get_num(&self) -> u8;
-    fn new(day_num: u8) -> Self;
-    fn part1(&mut self, input: &Self::Input) -> (String, bool);
-    fn part2(&mut self, input: &Self::Input) -> (String, bool);
-    fn parse_input(&mut self, input: &String) -> Self::Input;
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Answer;
+    fn part2(input: &Self::Input) -> Answer;
 }
 
-fn nanos_to_most_convenient(nanos: u128) -> (f64, String) {
-    let mut nanos = nanos as f64;
-    let mut unit = "ns";
-    if nanos > 1_000_000_000f64 {
-        nanos /= 1_000_000_000f64;
-        unit = "s";
-    } else if nanos > 1_000_000f64 {
-        nanos /= 1_000_000f64;
-        unit = "ms";
-    } else if nanos > 1_000f64 {
-        nanos /= 1_000f64;
-        unit = "us";
-    }
-    (nanos, unit.to_string())
-}
+/// Something that can work with any [`Day`], see [`with_day`].
+pub trait DayVisitor {
+    type Output;
 
-pub fn nanos_to_string(nanos: u128) -> ColoredString {
-    let (num, unit) = nanos_to_most_convenient(nanos);
-    if nanos < 500_000_000 {
-        format!("{} {}", num, unit).white()
-    } else {
-        format!("{} {}", num, unit).red()
-    }
+    fn visit<D: Day>(self, day_num: u8) -> Self::Output;
 }
 
-fn submit(
-    day: &mut impl Day,
-    level: u8,
-    answer: &String,
-    session: &String,
-    client: &reqwest::blocking::Client,
-) -> bool {
-    let mut form = HashMap::new();
-    form.insert("level", level.to_string());
-    form.insert("answer", answer.to_string());
-
-    match client
-        .post(format!(
-            "https://adventofcode.com/2022/day/{}/answer",
-            day.get_num()
-        ))
-        .header("Cookie", format!("session={}", session))
-        .header(
-            "User-Agent",
-            "BlockOG's AoC 2022 solutions at https://github.com/BlockOG/AoC2022",
-        )
-        .form(&form)
-        .send()
-    {
-        Ok(resp) => {
-            let resp = resp.text().unwrap();
-            let mut file =
-                File::create(format!("logs/submit{}_{}.txt", day.get_num(), level)).unwrap();
-            file.write_all(resp.as_bytes()).unwrap();
-            if resp.contains("one gold star") {
-                println!("{}", "Answer correct!".bold().green());
-                return true;
-            } else {
-                println!("{}", "Answer incorrect :(".bold().red());
-                return false;
-            }
-        }
-        Err(_) => {
-            println!("{}", "Could not submit answer".bold().red());
-            return false;
-        }
-    };
+/// The answer to one part of a day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Integer(i64),
+    Text(String),
+    /// A picture the answer has to be read from, like day 10's CRT, one line per row.
+    Art(String),
+    /// The part has no answer, like the second part of the last day.
+    NotApplicable,
 }
 
-fn run_impled_day(
-    day: &mut impl Day,
-    time: bool,
-    dont_print: bool,
-    dont_submit: bool,
-    dont_input: bool,
-    client: &reqwest::blocking::Client,
-) -> Option<(u128, u128, u128)> {
-    let mut session = String::new();
-    if !dont_submit || !dont_input {
-        match File::open("inputs/session.txt") {
-            Ok(mut file) => match file.read_to_string(&mut session) {
-                Ok(_) => (),
-                Err(_) => {
-                    println!(
-                        "{}",
-                        "Could not read session file (inputs/session.txt)"
-                            .bold()
-                            .red()
-                    );
-                    return None;
-                }
-            },
-            Err(_) => {
-                println!(
-                    "{}",
-                    "Could not open session file (inputs/session.txt)"
-                        .bold()
-                        .red()
-                );
-                return None;
-            }
+impl Answer {
+    /// What to submit to the site, `None` if the answer can't be submitted as is.
+    pub fn submission(&self) -> Option<String> {
+        match self {
+            Answer::Integer(n) => Some(n.to_string()),
+            Answer::Text(text) => Some(text.clone()),
+            Answer::Art(_) | Answer::NotApplicable => None,
         }
-        session = session.trim().to_string();
     }
 
-    let mut completed = 0;
-    let mut part1_answer = String::new();
-    let mut part2_answer = String::new();
-    let answer_regex = Regex::new(r"<p>Your puzzle answer was <code>(.+)</code>\.</p>").unwrap();
-    if !dont_submit {
-        match client
-            .get(&format!(
-                "https://adventofcode.com/2022/day/{}",
-                day.get_num()
-            ))
-            .header("Cookie", format!("session={}", session))
-            .header(
-                "User-Agent",
-                "BlockOG's AoC 2022 solutions at https://github.com/BlockOG/AoC2022",
-            )
-            .send()
-        {
-            Ok(response) => match response.text() {
-                Ok(text) => {
-                    let mut matches = answer_regex.captures_iter(&text);
-                    if let Some(match1) = matches.next() {
-                        part1_answer = match1[1].to_string();
-                        completed += 1;
-                    }
-                    if let Some(match2) = matches.next() {
-                        part2_answer = match2[1].to_string();
-                        completed += 1;
-                    }
-                    if text.contains("<p class=\"day-success\">Both parts of this puzzle are complete! They provide two gold stars: **</p>") {
-                        completed = 2;
-                    }
-                }
-                Err(_) => {
-                    println!("{}", "Could not get star amount".bold().red());
-                    return None;
-                }
-            },
-            Err(err) => {
-                println!("{}", "Could not download star amount".bold().red());
-                println!("{}", err);
-                return None;
-            }
-        };
+    pub fn is_submittable(&self) -> bool {
+        matches!(self, Answer::Integer(_) | Answer::Text(_))
     }
 
-    let mut input = String::new();
-    let input_file_path = format!("inputs/input{}.txt", day.get_num());
-    match File::open(&input_file_path) {
-        Ok(mut file) => match file.read_to_string(&mut input) {
-            Ok(_) => (),
-            Err(_) => {
-                println!(
-                    "{}",
-                    format!("Could not read input file ({})", input_file_path)
-                        .bold()
-                        .red()
-                );
-                return None;
-            }
-        },
-        Err(_) => {
-            if dont_input {
-                println!(
-                    "{}",
-                    format!("Could not open input file ({})", input_file_path)
-                        .bold()
-                        .red()
-                );
-                return None;
-            } else {
-                println!("{}", "Downloading input...".bold());
-                input = match client
-                    .get(&format!(
-                        "https://adventofcode.com/2022/day/{}/input",
-                        day.get_num()
-                    ))
-                    .header("Cookie", format!("session={}", session))
-                    .header(
-                        "User-Agent",
-                        "BlockOG's AoC 2022 solutions at https://github.com/BlockOG/AoC2022",
-                    )
-                    .send()
-                {
-                    Ok(response) => match response.text() {
-                        Ok(text) => text.trim_end().to_string(),
-                        Err(_) => {
-                            println!("{}", "Could not read input".bold().red());
-                            return None;
-                        }
-                    },
-                    Err(err) => {
-                        println!("{}", "Could not download input".bold().red());
-                        println!("{}", err);
-                        return None;
-                    }
-                };
-                println!("{}", "Input downloaded".bold());
-                match File::create(&input_file_path) {
-                    Ok(mut file) => match file.write_all(input.as_bytes()) {
-                        Ok(_) => (),
-                        Err(_) => {
-                            println!(
-                                "{}",
-                                format!("Could not write input file ({})", input_file_path)
-                                    .bold()
-                                    .red()
-                            );
-                            return None;
-                        }
-                    },
-                    Err(_) => {
-                        println!(
-                            "{}",
-                            format!("Could not create input file ({})", input_file_path)
-                                .bold()
-                                .red()
-                        );
-                        return None;
-                    }
+    /// Whether this is `expected`, an answer from the site or a file. Only submittable answers
+    /// can be.
+    pub fn matches(&self, expected: &str) -> bool {
+        self.submission()
+            .is_some_and(|submission| submission == expected)
+    }
+}
+
+macro_rules! integer_answers {
+    ($($int:ty),+) => {
+        $(
+            impl From<$int> for Answer {
+                fn from(n: $int) -> Self {
+                    Answer::Integer(n.into())
                 }
             }
-        }
-    }
-    input = input.replace("\r\n", "\n");
+        )+
+    };
+}
 
-    let start_parsing = Instant::now();
-    let parsed_input = day.parse_input(&input);
-    let elapsed_parsing = start_parsing.elapsed().as_nanos();
+integer_answers!(i8, i16, i32, i64, u8, u16, u32);
+
+/// Numbers too big for an [`Answer::Integer`] become its text, which is submitted the same.
+///
+/// ```
+/// use advent_of_code::Answer;
+///
+/// assert_eq!(Answer::from(42u64), Answer::Integer(42));
+/// assert_eq!(Answer::from(u64::MAX), Answer::Text(u64::MAX.to_string()));
+/// ```
+impl From<u64> for Answer {
+    fn from(n: u64) -> Self {
+        i64::try_from(n).map_or_else(|_| Answer::Text(n.to_string()), Answer::Integer)
+    }
+}
 
-    if completed > 0 {
-        println!(
-            "{} {}",
-            format!("Day {}", day.get_num()).bold().green(),
-            "*".repeat(completed).bold().yellow()
-        );
-    } else {
-        println!("{}", format!("Day {}", day.get_num()).bold().green());
+impl From<usize> for Answer {
+    fn from(n: usize) -> Self {
+        i64::try_from(n).map_or_else(|_| Answer::Text(n.to_string()), Answer::Integer)
     }
+}
 
-    let start_part1 = Instant::now();
-    let part1 = day.part1(&parsed_input);
-    let elapsed_part1 = start_part1.elapsed().as_nanos();
-    if !dont_print {
-        if part1_answer.is_empty() || !part1.1 {
-            println!("{} {}", "Part 1:".bold(), part1.0);
-        } else {
-            if part1.0 == part1_answer {
-                println!("{} {}", "Part 1:".bold(), part1.0.green());
-            } else {
-                println!("{} {}", "Part 1:".bold(), part1.0.red());
-                println!("        {}", part1_answer.green());
-            }
-        }
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
     }
-    let mut failed_submission = false;
-    if part1.1 && !dont_submit && completed < 1 {
-        println!("{}", "Submitting part 1...".bold());
-        failed_submission = !submit(day, 1, &part1.0, &session, client);
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::Text(text.to_string())
     }
+}
 
-    let start_part2 = Instant::now();
-    let part2 = day.part2(&parsed_input);
-    let elapsed_part2 = start_part2.elapsed().as_nanos();
-    if !dont_print {
-        if part2_answer.is_empty() || !part2.1 {
-            println!("{} {}", "Part 2:".bold(), part2.0);
-        } else {
-            if part2.0 == part2_answer {
-                println!("{} {}", "Part 2:".bold(), part2.0.green());
-            } else {
-                println!("{} {}", "Part 2:".bold(), part2.0.red());
-                println!("        {}", part2_answer.green());
-            }
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Integer(n) => write!(f, "{}", n),
+            Answer::Text(text) | Answer::Art(text) => write!(f, "{}", text),
+            Answer::NotApplicable => write!(f, "n/a"),
         }
     }
-    if part2.1 && !dont_submit && completed < 2 && !failed_submission {
-        println!("{}", "Submitting part 2...".bold());
-        if submit(day, 2, &part2.0, &session, client) {
-            println!("{}", "Day completed!".bold().green());
-        }
+}
+
+/// The message a panic was started with, as far as it can be told.
+pub fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+/// Parses `input` for `D`, day `day_num`.
+pub fn parse_input<D: Day>(day_num: u8, input: &str) -> Result<D::Input, Error> {
+    D::parse(input).map_err(|source| Error::Parse {
+        day: day_num,
+        source: source.locate(input),
+    })
+}
+
+/// Runs one part of `D`, day `day_num`, turning a panic into [`Error::Solver`].
+pub fn solve_part<D: Day>(day_num: u8, part: u8, input: &D::Input) -> Result<Answer, Error> {
+    panic::catch_unwind(AssertUnwindSafe(|| match part {
+        1 => D::part1(input),
+        2 => D::part2(input),
+        _ => unreachable!("There are only two parts"),
+    }))
+    .map_err(|payload| Error::Solver {
+        day: day_num,
+        part,
+        message: panic_message(payload),
+    })
+}
+
+struct Solve<'a> {
+    part: u8,
+    input: &'a str,
+}
+
+impl DayVisitor for Solve<'_> {
+    type Output = Result<Answer, Error>;
+
+    fn visit<D: Day>(self, day_num: u8) -> Self::Output {
+        let input = parse_input::<D>(day_num, self.input)?;
+        solve_part::<D>(day_num, self.part, &input)
     }
+}
 
-    if time {
-        println!(
-            "{} {}",
-            "Parsing Time:".bold(),
-            nanos_to_string(elapsed_parsing)
-        );
-        println!(
-            "{} {}",
-            "Part 1 Time:".bold(),
-            nanos_to_string(elapsed_part1)
-        );
-        println!(
-            "{} {}",
-            "Part 2 Time:".bold(),
-            nanos_to_string(elapsed_part2)
-        );
+/// Solves `part` of `day` of `year` for `input`, without touching the network or the terminal.
+///
+/// ```
+/// let answer = advent_of_code::solve(2022, 1, 1, "1000\n2000\n\n4000").unwrap();
+/// assert_eq!(answer, advent_of_code::Answer::Integer(4000));
+/// ```
+pub fn solve(year: u16, day: u8, part: u8, input: &str) -> Result<Answer, Error> {
+    if !(1..=2).contains(&part) {
+        return Err(Error::InvalidPart(part));
     }
-    Some((elapsed_parsing, elapsed_part1, elapsed_part2))
+    with_day(year, day, Solve { part, input })?
+}
+
+/// The latest year with solutions, the one used when none is asked for.
+pub fn latest_year() -> u16 {
+    YEARS[YEARS.len() - 1]
 }
 
-run_day!(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25);
+run_day!(
+    2022 => [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25],
+);
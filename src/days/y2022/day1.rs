@@ -0,0 +1,25 @@
+use itertools::Itertools;
+
+use crate::days::{self, Answer};
+use crate::error::ParseError;
+use crate::parse;
+
+pub struct Day;
+
+impl days::Day for Day {
+    type Input = Vec<i32>;
+
+    fn part1(input: &Self::Input) -> Answer {
+        input.iter().max().copied().unwrap_or(0).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        input.iter().sorted().rev().take(3).sum::<i32>().into()
+    }
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse::blocks(input)
+            .map(|elf| elf.lines().map(parse::parse::<i32>).sum())
+            .collect()
+    }
+}
@@ -1,4 +1,7 @@
-use crate::days;
+use crate::days::{self, Answer};
+use crate::error::ParseError;
+use crate::ocr;
+use crate::parse;
 
 pub enum Instruction {
     Noop,      // example: noop
@@ -6,35 +9,27 @@ pub enum Instruction {
 }
 
 impl Instruction {
-    fn parse(input: &str) -> Self {
+    fn parse(input: &str) -> Result<Self, ParseError> {
         let mut parts = input.split_whitespace();
-        let instruction = parts.next().unwrap();
-        match instruction {
-            "noop" => Self::Noop,
-            "addx" => Self::AddX(parts.next().unwrap().parse().unwrap()),
-            _ => panic!("Unknown instruction"),
+        match parts.next() {
+            Some("noop") => Ok(Self::Noop),
+            Some("addx") => {
+                let value = parts
+                    .next()
+                    .ok_or_else(|| ParseError::new("addx is missing its argument").at(input))?;
+                Ok(Self::AddX(parse::parse(value)?))
+            }
+            _ => Err(ParseError::new(format!("unknown instruction `{}`", input)).at(input)),
         }
     }
 }
 
-pub struct Day {
-    day_num: u8,
-}
+pub struct Day;
 
 impl days::Day for Day {
     type Input = Vec<Instruction>;
 
-    fn get_num(&self) -> u8 {
-        self.day_num
-    }
-
-    fn new(day_num: u8) -> Self {
-        Self {
-            day_num
-        }
-    }
-
-    fn part1(&mut self, input: &Self::Input) -> (String, bool) {
+    fn part1(input: &Self::Input) -> Answer {
         let mut signal_strenghs = 0;
         let mut cycle = 1;
         let mut x = 1;
@@ -54,10 +49,10 @@ impl days::Day for Day {
                 }
             }
         }
-        (signal_strenghs.to_string(), true)
+        signal_strenghs.into()
     }
 
-    fn part2(&mut self, input: &Self::Input) -> (String, bool) {
+    fn part2(input: &Self::Input) -> Answer {
         let mut cycle = 1;
         let mut x: i32 = 1;
         let mut crt = vec![String::new()];
@@ -88,15 +83,19 @@ impl days::Day for Day {
             }
         }
         crt.pop();
-        (
-            crt.iter().fold(String::new(), |acc, x| {
-                acc + "\n" + &x.replace("#", "██").replace(" ", "  ")
-            }),
-            false,
-        )
+        match ocr::read_letters(&crt, '#') {
+            Some(letters) => letters.into(),
+            // Like the example, which isn't letters.
+            None => Answer::Art(
+                crt.iter()
+                    .map(|x| x.replace('#', "██").replace(' ', "  "))
+                    .collect::<Vec<_>>()
+                    .join("\n"),
+            ),
+        }
     }
 
-    fn parse_input(&mut self, input: &String) -> Self::Input {
-        input.lines().map(|line| Instruction::parse(line)).collect()
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.lines().map(Instruction::parse).collect()
     }
 }
@@ -0,0 +1,166 @@
+use itertools::Itertools;
+
+use crate::days::{self, Answer};
+use crate::error::ParseError;
+use crate::parse::{self, Fields};
+
+#[derive(Debug, Clone)]
+enum Operation {
+    Add(u64),
+    Multiply(u64),
+    Square,
+}
+
+#[derive(Debug, Clone)]
+struct Test {
+    divisor: u64,
+    true_monkey: u8,
+    false_monkey: u8,
+}
+
+#[derive(Debug, Clone)]
+pub struct Monkey {
+    items: Vec<u64>,
+    operation: Operation,
+    test: Test,
+}
+
+/// The number of a monkey to throw to in `fields`, one of the `count` monkeys.
+fn target(fields: &Fields, count: usize) -> Result<u8, ParseError> {
+    let monkey = fields.parse(0)?;
+    if usize::from(monkey) >= count {
+        return Err(ParseError::new(format!("there is no monkey {}", monkey)).at(fields.str(0)));
+    }
+    Ok(monkey)
+}
+
+/// The next line of a monkey's `block`, matched against `pattern`.
+fn next_line<'a>(
+    block: &'a str,
+    lines: &mut impl Iterator<Item = &'a str>,
+    pattern: &str,
+) -> Result<Fields<'a>, ParseError> {
+    let name = pattern.split(':').next().unwrap_or(pattern);
+    let line = lines.next().ok_or_else(|| {
+        ParseError::new(format!("missing `{}` line", name)).at(&block[block.len()..])
+    })?;
+    parse::template(pattern, line.trim())
+}
+
+pub struct Day;
+
+impl days::Day for Day {
+    type Input = Vec<Monkey>;
+
+    fn part1(input: &Self::Input) -> Answer {
+        let mut monkeys = input.clone();
+        let mut inspects = vec![0; monkeys.len()];
+        for _ in 0..20 {
+            for i in 0..monkeys.len() {
+                for item_index in (0..monkeys[i].items.len()).rev() {
+                    let item = monkeys[i].items[item_index];
+                    let new_item = match monkeys[i].operation {
+                        Operation::Add(n) => item + n,
+                        Operation::Multiply(n) => item * n,
+                        Operation::Square => item * item,
+                    } / 3;
+                    inspects[i] += 1;
+                    monkeys[i].items.pop();
+                    if new_item % monkeys[i].test.divisor == 0 {
+                        let true_monkey = monkeys[i].test.true_monkey as usize;
+                        monkeys[true_monkey].items.push(new_item);
+                    } else {
+                        let false_monkey = monkeys[i].test.false_monkey as usize;
+                        monkeys[false_monkey].items.push(new_item);
+                    }
+                }
+            }
+        }
+        inspects
+            .iter()
+            .sorted()
+            .rev()
+            .take(2)
+            .product::<u64>()
+            .into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        let mut monkeys = input.clone();
+        let test_lcm = monkeys.iter().map(|m| m.test.divisor).product::<u64>();
+        let mut inspects = vec![0; monkeys.len()];
+        for _ in 0..10000 {
+            for i in 0..monkeys.len() {
+                for item_index in (0..monkeys[i].items.len()).rev() {
+                    let item = monkeys[i].items[item_index];
+                    let new_item = match monkeys[i].operation {
+                        Operation::Add(n) => item + n,
+                        Operation::Multiply(n) => item * n,
+                        Operation::Square => item * item,
+                    } % test_lcm;
+                    inspects[i] += 1;
+                    monkeys[i].items.pop();
+                    if new_item % monkeys[i].test.divisor == 0 {
+                        let true_monkey = monkeys[i].test.true_monkey as usize;
+                        monkeys[true_monkey].items.push(new_item);
+                    } else {
+                        let false_monkey = monkeys[i].test.false_monkey as usize;
+                        monkeys[false_monkey].items.push(new_item);
+                    }
+                }
+            }
+        }
+        inspects
+            .iter()
+            .sorted()
+            .rev()
+            .take(2)
+            .product::<u64>()
+            .into()
+    }
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let count = parse::blocks(input).count();
+        parse::blocks(input)
+            .enumerate()
+            .map(|(i, block)| {
+                let mut lines = block.lines();
+                let number = next_line(block, &mut lines, "Monkey {}:")?;
+                if number.parse::<usize>(0)? != i {
+                    return Err(ParseError::new(format!("expected monkey {}", i)).at(number.str(0)));
+                }
+                let items = next_line(block, &mut lines, "Starting items: {}")?;
+                let items = items
+                    .str(0)
+                    .split(',')
+                    .map(parse::parse)
+                    .collect::<Result<_, _>>()?;
+
+                let operation = next_line(block, &mut lines, "Operation: new = old {} {}")?;
+                let operation = match (operation.str(0), operation.str(1)) {
+                    ("*", "old") => Operation::Square,
+                    ("*", _) => Operation::Multiply(operation.parse(1)?),
+                    ("+", _) => Operation::Add(operation.parse(1)?),
+                    (unknown, _) => {
+                        return Err(
+                            ParseError::new(format!("unknown operation `{}`", unknown)).at(unknown)
+                        )
+                    }
+                };
+
+                let divisor = next_line(block, &mut lines, "Test: divisible by {}")?;
+                let true_monkey = next_line(block, &mut lines, "If true: throw to monkey {}")?;
+                let false_monkey = next_line(block, &mut lines, "If false: throw to monkey {}")?;
+                Ok(Monkey {
+                    items,
+                    operation,
+                    test: Test {
+                        divisor: divisor.parse(0)?,
+                        true_monkey: target(&true_monkey, count)?,
+                        false_monkey: target(&false_monkey, count)?,
+                    },
+                })
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,74 @@
+use pathfinding::prelude::{astar, dijkstra};
+
+use crate::days::{self, Answer};
+use crate::error::ParseError;
+use crate::grid::{Grid, Pos};
+
+fn char2elevation(c: char) -> usize {
+    c as usize - 'a' as usize
+}
+
+/// The positions the path can come from to climb onto `pos`.
+fn predecessors(pos: Pos, grid: &Grid<usize>) -> Vec<(Pos, usize)> {
+    let next_elevation = grid[pos].saturating_sub(1);
+    grid.neighbours(pos)
+        .filter(|&neighbour| grid[neighbour] >= next_elevation)
+        .map(|neighbour| (neighbour, 1))
+        .collect()
+}
+
+pub struct Day;
+
+impl days::Day for Day {
+    type Input = (Pos, Pos, Grid<usize>);
+
+    fn part1(input: &Self::Input) -> Answer {
+        let (start, end, grid) = input;
+
+        astar(
+            end,
+            |&current| predecessors(current, grid),
+            |&current| current.manhattan(*start) as usize,
+            |&current| current == *start,
+        )
+        .unwrap()
+        .1
+        .into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        let (_, end, grid) = input;
+
+        dijkstra(
+            end,
+            |&current| predecessors(current, grid),
+            |&current| grid[current] == 0,
+        )
+        .unwrap()
+        .1
+        .into()
+    }
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut start = None;
+        let mut end = None;
+        let grid = Grid::parse(input, |pos, c| match c {
+            'S' => {
+                start = Some(pos);
+                Ok(0)
+            }
+            'E' => {
+                end = Some(pos);
+                Ok(25)
+            }
+            'a'..='z' => Ok(char2elevation(c)),
+            _ => Err(ParseError::new(format!("invalid elevation `{}`", c))),
+        })?;
+        let input_end = &input[input.len()..];
+        let start =
+            start.ok_or_else(|| ParseError::new("missing start position `S`").at(input_end))?;
+        let end =
+            end.ok_or_else(|| ParseError::new("missing best signal position `E`").at(input_end))?;
+        Ok((start, end, grid))
+    }
+}
@@ -1,9 +1,11 @@
 use std::{
     cmp::Ordering,
     fmt::{Display, Formatter, Result},
+    str::Chars,
 };
 
-use crate::days;
+use crate::days::{self, Answer};
+use crate::error::ParseError;
 
 #[derive(Debug, Eq, PartialEq)]
 pub enum Value {
@@ -12,16 +14,17 @@ pub enum Value {
 }
 
 impl Value {
-    fn parsing(input: &mut impl Iterator<Item = char>) -> Self {
+    fn parsing(input: &mut Chars) -> std::result::Result<Self, ParseError> {
         let mut list = vec![];
         let mut num = -1;
         let mut stack = vec![];
         loop {
+            let rest = input.as_str();
             match input.next() {
                 Some('[') => {
                     stack.push(Value::List(list));
                     list = vec![];
-                },
+                }
                 Some(']') => {
                     if num != -1 {
                         list.push(Value::Number(num));
@@ -31,7 +34,7 @@ impl Value {
                         l.push(Value::List(list));
                         list = l;
                     }
-                },
+                }
                 Some(',') => {
                     if num != -1 {
                         list.push(Value::Number(num));
@@ -43,21 +46,26 @@ impl Value {
                         num = 0;
                     }
                     num *= 10;
-                    num += c.to_digit(10).unwrap() as i64;
-                },
+                    num += c.to_digit(10).ok_or_else(|| {
+                        ParseError::new(format!("unexpected character `{}` in packet", c))
+                            .at(&rest[..c.len_utf8()])
+                    })? as i64;
+                }
                 None => break,
             }
         }
         if num != -1 {
             list.push(Value::Number(num));
         }
-        Value::List(list)
+        Ok(Value::List(list))
     }
 
-    fn parse(input: &str) -> Self {
-        let mut input = input.chars();
-        input.next(); // Skip the first [
-        Value::parsing(&mut input)
+    fn parse(input: &str) -> std::result::Result<Self, ParseError> {
+        let mut chars = input.chars();
+        if chars.next() != Some('[') {
+            return Err(ParseError::new("packets must start with `[`").at(input));
+        }
+        Value::parsing(&mut chars)
     }
 }
 
@@ -118,34 +126,22 @@ impl Value {
     }
 }
 
-pub struct Day {
-    day_num: u8,
-}
+pub struct Day;
 
 impl days::Day for Day {
     type Input = Vec<(Value, Value)>;
 
-    fn get_num(&self) -> u8 {
-        self.day_num
-    }
-
-    fn new(day_num: u8) -> Self {
-        Self {
-            day_num
-        }
-    }
-
-    fn part1(&mut self, input: &Self::Input) -> (String, bool) {
+    fn part1(input: &Self::Input) -> Answer {
         let mut sum = 0;
         for (i, (a, b)) in input.iter().enumerate() {
             if a.cmp(b).is_le() {
                 sum += i + 1;
             }
         }
-        (sum.to_string(), true)
+        sum.into()
     }
 
-    fn part2(&mut self, input: &Self::Input) -> (String, bool) {
+    fn part2(input: &Self::Input) -> Answer {
         let packets = input.iter().fold(vec![], |mut acc, (a, b)| {
             acc.push(a);
             acc.push(b);
@@ -166,18 +162,17 @@ impl days::Day for Day {
             }
         }
 
-        ((two_index * six_index).to_string(), true)
+        (two_index * six_index).into()
     }
 
-    fn parse_input(&mut self, input: &String) -> Self::Input {
+    fn parse(input: &str) -> std::result::Result<Self::Input, ParseError> {
         input
             .split("\n\n")
             .map(|x| {
-                let mut split = x.split("\n");
-                (
-                    Value::parse(split.next().unwrap()),
-                    Value::parse(split.next().unwrap()),
-                )
+                let (a, b) = x
+                    .split_once('\n')
+                    .ok_or_else(|| ParseError::new("expected a pair of packets").at(x))?;
+                Ok((Value::parse(a)?, Value::parse(b)?))
             })
             .collect()
     }
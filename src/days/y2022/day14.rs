@@ -0,0 +1,168 @@
+use crate::days::{self, Answer};
+use crate::error::ParseError;
+use crate::grid::{Grid, Pos};
+use crate::parse;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Went {
+    Down,
+    DownLeft,
+    DownRight,
+}
+
+impl Went {
+    fn next(&self) -> Option<Self> {
+        match self {
+            Went::Down => Some(Went::DownLeft),
+            Went::DownLeft => Some(Went::DownRight),
+            Went::DownRight => None,
+        }
+    }
+
+    fn offset(&self) -> Pos {
+        match self {
+            Went::Down => Pos::new(0, 1),
+            Went::DownLeft => Pos::new(-1, 1),
+            Went::DownRight => Pos::new(1, 1),
+        }
+    }
+}
+
+fn misses_block(blocks: &Grid<bool>, max_y: i32, block: Pos) -> bool {
+    if block.y >= max_y + 2 {
+        return false;
+    }
+    blocks.get(block).copied().unwrap_or(true)
+}
+
+/// The rocks once for each part, they look at them differently. Each part fills its own copy with
+/// sand.
+#[derive(Debug, Clone)]
+pub struct Cave {
+    /// Whether sand can fall through a tile.
+    blocksp1: Grid<bool>,
+    /// Whether a tile is rock.
+    blocksp2: Grid<bool>,
+
+    max_y: i32,
+}
+
+pub struct Day;
+
+impl days::Day for Day {
+    type Input = Cave;
+
+    fn part1(input: &Self::Input) -> Answer {
+        let mut blocksp1 = input.blocksp1.clone();
+        let max_y = input.max_y;
+        let mut rested_sand = 0;
+        let mut path = vec![];
+
+        let mut curr = Pos::new(500, 0);
+        let mut didnt_all = true;
+        let mut curr_dir = Some(Went::Down);
+
+        while curr.y <= max_y {
+            if let Some(dir) = curr_dir {
+                let followed = curr + dir.offset();
+                if misses_block(&blocksp1, max_y, followed) {
+                    path.push(dir);
+                    didnt_all = true;
+                    curr = followed;
+                    curr_dir = Some(Went::Down);
+                } else {
+                    curr_dir = dir.next();
+                }
+            } else {
+                if didnt_all {
+                    rested_sand += 1;
+                    blocksp1[curr] = false;
+                }
+                let last = path.pop().unwrap();
+                curr -= last.offset();
+                curr_dir = Some(last);
+                didnt_all = false;
+            }
+        }
+        rested_sand.into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        let mut blocksp2 = input.blocksp2.clone();
+        let width = blocksp2.width() as i32;
+        let mut rested_sand = 0;
+        let mut current_layer = 1;
+
+        for y in 0..input.max_y + 2 {
+            rested_sand += current_layer;
+            rested_sand -= (0..width).filter(|&x| blocksp2[Pos::new(x, y)]).count();
+
+            for x in 0..width - 2 {
+                if (x..x + 3).all(|x| blocksp2[Pos::new(x, y)]) {
+                    blocksp2[Pos::new(x + 1, y + 1)] = true;
+                }
+            }
+
+            current_layer += 2;
+        }
+
+        rested_sand.into()
+    }
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let paths = input
+            .lines()
+            .map(|line| {
+                let mut poses: Vec<(&str, Pos)> = vec![];
+                for point in line.split(" -> ") {
+                    let [x, y] = parse::integers_n::<2, _>(point)?;
+                    let pos = Pos::new(x, y);
+                    if pos.x <= 0 || pos.x >= 599 || pos.y < 0 || pos.y >= 198 {
+                        return Err(ParseError::new(format!(
+                            "position `{}` is out of bounds",
+                            point
+                        ))
+                        .at(point));
+                    }
+                    if let Some(&(last, last_pos)) = poses.last() {
+                        if last_pos.x != pos.x && last_pos.y != pos.y {
+                            let start = last.as_ptr() as usize - line.as_ptr() as usize;
+                            let end =
+                                point.as_ptr() as usize - line.as_ptr() as usize + point.len();
+                            let segment = &line[start..end];
+                            return Err(ParseError::new(format!(
+                                "the segment `{}` is neither horizontal nor vertical",
+                                segment
+                            ))
+                            .at(segment));
+                        }
+                    }
+                    poses.push((point, pos));
+                }
+                Ok(poses.into_iter().map(|(_, pos)| pos).collect())
+            })
+            .collect::<Result<Vec<Vec<Pos>>, ParseError>>()?;
+
+        let mut cave = Cave {
+            blocksp1: Grid::new(600, 200, true),
+            blocksp2: Grid::new(600, 200, false),
+
+            max_y: 0,
+        };
+        for path in paths.iter() {
+            for window in path.windows(2) {
+                let (mut pos, end) = (window[0], window[1]);
+                cave.max_y = cave.max_y.max(pos.y).max(end.y);
+                let step = Pos::new((end.x - pos.x).signum(), (end.y - pos.y).signum());
+                while pos != end {
+                    cave.blocksp1[pos] = false;
+                    cave.blocksp2[pos] = true;
+                    pos += step;
+                }
+                cave.blocksp1[pos] = false;
+                cave.blocksp2[pos] = true;
+            }
+        }
+        Ok(cave)
+    }
+}
@@ -1,6 +1,8 @@
 use std::collections::HashSet;
 
-use crate::days;
+use crate::days::{self, Answer};
+use crate::error::ParseError;
+use crate::parse;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 struct Pos {
@@ -97,24 +99,12 @@ impl Sensor {
     }
 }
 
-pub struct Day {
-    day_num: u8,
-}
+pub struct Day;
 
 impl days::Day for Day {
     type Input = (Vec<Sensor>, i64, i64, i64, i64, u64, u32);
 
-    fn get_num(&self) -> u8 {
-        self.day_num
-    }
-
-    fn new(day_num: u8) -> Self {
-        Self {
-            day_num
-        }
-    }
-
-    fn part1(&mut self, input: &Self::Input) -> (String, bool) {
+    fn part1(input: &Self::Input) -> Answer {
         let sensors = &input.0;
         let min_x = input.1 - input.5 as i64;
         let max_x = input.3 + input.5 as i64;
@@ -160,10 +150,10 @@ impl days::Day for Day {
 
         isnt_in -= input.6 as i64;
 
-        (isnt_in.to_string(), true)
+        isnt_in.into()
     }
 
-    fn part2(&mut self, input: &Self::Input) -> (String, bool) {
+    fn part2(input: &Self::Input) -> Answer {
         let sensors = &input.0;
 
         let mut last_line = None;
@@ -184,16 +174,14 @@ impl days::Day for Day {
                         (sensor1.pos.x < sensor2.pos.x) ^ (sensor1.pos.y < sensor2.pos.y),
                     );
 
-                    if last_line.is_none() {
-                        last_line = Some(line);
+                    if let Some(last_line) = last_line {
+                        return line
+                            .intersects(&last_line)
+                            .unwrap()
+                            .tuning_frequency()
+                            .into();
                     } else {
-                        return (
-                            line.intersects(&last_line.unwrap())
-                                .unwrap()
-                                .tuning_frequency()
-                                .to_string(),
-                            true,
-                        );
+                        last_line = Some(line);
                     }
                 }
             }
@@ -202,7 +190,7 @@ impl days::Day for Day {
         panic!("What did you do with the input?");
     }
 
-    fn parse_input(&mut self, input: &String) -> Self::Input {
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
         let mut sensors = vec![];
 
         let mut min_x = i64::MAX;
@@ -215,14 +203,14 @@ impl days::Day for Day {
         let mut beacons_on_2000000 = HashSet::new();
 
         for line in input.lines() {
-            let mut split = line.split(": ");
+            let fields = parse::template(
+                "Sensor at x={}, y={}: closest beacon is at x={}, y={}",
+                line,
+            )?;
             let sensor_pos;
             let beacon_pos;
             {
-                let mut split = split.next().unwrap().split("=");
-                split.next();
-                let x = split.next().unwrap().replace(", y", "").parse().unwrap();
-                let y = split.next().unwrap().parse().unwrap();
+                let (x, y) = (fields.parse(0)?, fields.parse(1)?);
                 sensor_pos = Pos::new(x, y);
 
                 min_x = min_x.min(x);
@@ -231,10 +219,7 @@ impl days::Day for Day {
                 max_y = max_y.max(y);
             }
             {
-                let mut split = split.next().unwrap().split("=");
-                split.next();
-                let x = split.next().unwrap().replace(", y", "").parse().unwrap();
-                let y = split.next().unwrap().parse().unwrap();
+                let (x, y) = (fields.parse(2)?, fields.parse(3)?);
                 beacon_pos = Pos::new(x, y);
 
                 min_x = min_x.min(x);
@@ -250,7 +235,10 @@ impl days::Day for Day {
             sensors.push(Sensor::new(sensor_pos, beacon_pos));
         }
 
-        (
+        if sensors.is_empty() {
+            return Err(ParseError::new("there are no sensors").at(input));
+        }
+        Ok((
             sensors,
             min_x,
             min_y,
@@ -258,6 +246,6 @@ impl days::Day for Day {
             max_y,
             max_distance,
             beacons_on_2000000.len() as u32,
-        )
+        ))
     }
 }
@@ -1,11 +1,13 @@
-use crate::days;
+use crate::days::{self, Answer};
+use crate::error::ParseError;
+use crate::parse;
 
-fn choose_one<'a, T>(xs: &'a Vec<T>) -> impl Iterator<Item = (T, Vec<T>)> + 'a
+fn choose_one<T>(xs: &[T]) -> impl Iterator<Item = (T, Vec<T>)> + '_
 where
     T: Clone,
 {
     (0..xs.len()).map(move |i| {
-        let mut ys = xs.clone();
+        let mut ys = xs.to_vec();
         let element = ys.remove(i);
         (element, ys)
     })
@@ -17,12 +19,12 @@ fn dfs(
     flows: &Vec<u64>,
     dist: &Vec<Vec<u64>>,
     current_valve: u64,
-    closed_valves: &Vec<u64>,
+    closed_valves: &[u64],
     time_remaining: u64,
     cache: &mut HashMap<(u64, Vec<u64>, u64), u64>,
 ) -> u64 {
     // Check if the result is already in the cache
-    if let Some(result) = cache.get(&(current_valve, closed_valves.clone(), time_remaining)) {
+    if let Some(result) = cache.get(&(current_valve, closed_valves.to_vec(), time_remaining)) {
         return *result;
     }
 
@@ -44,7 +46,7 @@ fn dfs(
 
     // Add the result to the cache
     cache.insert(
-        (current_valve, closed_valves.clone(), time_remaining),
+        (current_valve, closed_valves.to_vec(), time_remaining),
         result,
     );
 
@@ -56,7 +58,7 @@ fn dfs_part2(
     flows: &Vec<u64>,
     dist: &Vec<Vec<u64>>,
     current_valve: u64,
-    closed_valves: &Vec<u64>,
+    closed_valves: &[u64],
     time_remaining: u64,
     cache: &mut HashMap<(u64, Vec<u64>, u64), u64>,
 ) -> u64 {
@@ -85,100 +87,75 @@ fn dfs_part2(
         ))
 }
 
-pub struct Day {
-    part1_cache: HashMap<(u64, Vec<u64>, u64), u64>,
-    day_num: u8,
-}
+pub struct Day;
 
 impl days::Day for Day {
     type Input = (Vec<String>, Vec<u64>, Vec<Vec<String>>, Vec<Vec<u64>>);
 
-    fn get_num(&self) -> u8 {
-        self.day_num
-    }
-
-    fn new(day_num: u8) -> Self {
-        Self {
-            part1_cache: HashMap::new(),
-            day_num,
-        }
-    }
-
-    fn part1(&mut self, input: &Self::Input) -> (String, bool) {
-        (
-            dfs(
-                &input.1,
-                &input.3,
-                input.0.iter().position(|x| x == &"AA".to_string()).unwrap() as u64,
-                &input
-                    .1
-                    .iter()
-                    .enumerate()
-                    .filter(|(_, f)| **f > 0)
-                    .map(|(i, _)| i as u64)
-                    .collect(),
-                30,
-                &mut self.part1_cache,
-            )
-            .to_string(),
-            true,
+    fn part1(input: &Self::Input) -> Answer {
+        dfs(
+            &input.1,
+            &input.3,
+            input.0.iter().position(|x| x == &"AA".to_string()).unwrap() as u64,
+            &input
+                .1
+                .iter()
+                .enumerate()
+                .filter(|(_, f)| **f > 0)
+                .map(|(i, _)| i as u64)
+                .collect::<Vec<_>>(),
+            30,
+            &mut HashMap::new(),
         )
+        .into()
     }
 
-    fn part2(&mut self, input: &Self::Input) -> (String, bool) {
-        (
-            dfs_part2(
-                &input.0,
-                &input.1,
-                &input.3,
-                input.0.iter().position(|x| x == &"AA".to_string()).unwrap() as u64,
-                &input
-                    .1
-                    .iter()
-                    .enumerate()
-                    .filter(|(_, f)| **f > 0)
-                    .map(|(i, _)| i as u64)
-                    .collect(),
-                26,
-                &mut self.part1_cache,
-            )
-            .to_string(),
-            true,
+    fn part2(input: &Self::Input) -> Answer {
+        dfs_part2(
+            &input.0,
+            &input.1,
+            &input.3,
+            input.0.iter().position(|x| x == &"AA".to_string()).unwrap() as u64,
+            &input
+                .1
+                .iter()
+                .enumerate()
+                .filter(|(_, f)| **f > 0)
+                .map(|(i, _)| i as u64)
+                .collect::<Vec<_>>(),
+            26,
+            &mut HashMap::new(),
         )
+        .into()
     }
 
-    fn parse_input(&mut self, input: &String) -> Self::Input {
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
         let mut names = vec![];
         let mut flow_rates = vec![];
         let mut connections = vec![];
         for line in input.lines() {
-            let mut leads_to = Vec::new();
-            for part in line.split(" ").skip(9) {
-                if part.ends_with(",") {
-                    leads_to.push(part[..part.len() - 1].to_string());
-                } else {
-                    leads_to.push(part.to_string());
-                }
-            }
-            connections.push(leads_to);
-
-            names.push(line.split(" ").nth(1).unwrap().to_string());
-
-            flow_rates.push(
-                line.split("=")
-                    .nth(1)
-                    .unwrap()
-                    .split(";")
-                    .next()
-                    .unwrap()
-                    .parse()
-                    .unwrap(),
-            );
+            let fields = parse::template("Valve {} has flow rate={}; {}", line)?;
+            let tunnels = fields.str(2);
+            let leads_to = tunnels
+                .strip_prefix("tunnels lead to valves ")
+                .or_else(|| tunnels.strip_prefix("tunnel leads to valve "))
+                .ok_or_else(|| {
+                    ParseError::new(format!("expected the tunnels in `{}`", tunnels)).at(tunnels)
+                })?;
+            connections.push(leads_to.split(", ").collect::<Vec<_>>());
+            names.push(fields.str(0).to_string());
+            flow_rates.push(fields.parse(1)?);
+        }
+        if !names.iter().any(|name| name == "AA") {
+            return Err(ParseError::new("missing starting valve `AA`"));
         }
         let mut dist = vec![vec![99; names.len()]; names.len()];
         for (i, cl) in connections.iter().enumerate() {
             for conn in cl.iter() {
-                dist[i][names.iter().position(|x| x == conn).unwrap()] = 1;
+                let j = names.iter().position(|x| x == conn).ok_or_else(|| {
+                    ParseError::new(format!("tunnel leads to unknown valve `{}`", conn)).at(conn)
+                })?;
+                dist[i][j] = 1;
             }
         }
         // Floyd-Warshall
@@ -189,6 +166,10 @@ impl days::Day for Day {
                 }
             }
         }
-        (names, flow_rates, connections, dist)
+        let connections = connections
+            .into_iter()
+            .map(|cl| cl.into_iter().map(str::to_string).collect())
+            .collect();
+        Ok((names, flow_rates, connections, dist))
     }
 }
@@ -0,0 +1,184 @@
+use crate::days::{self, Answer};
+use crate::error::ParseError;
+use crate::grid::{Dir, Pos, SparseGrid};
+
+/// The tower is kept upside down on the grid, `y` is the height above the floor.
+const FALL: Pos = Pos::new(0, -1);
+
+fn collides(pos: Pos, occupied: &SparseGrid<(usize, usize)>) -> bool {
+    if pos.y < 0 {
+        return true;
+    }
+    if pos.x < 0 || pos.x > 6 {
+        return true;
+    }
+    occupied.contains(pos)
+}
+
+fn max_y(shape: &[Pos]) -> i32 {
+    shape.iter().map(|p| p.y).max().unwrap()
+}
+
+fn repeats(
+    pos: &Pos,
+    shape: &[Pos],
+    occupied: &SparseGrid<(usize, usize)>,
+) -> Option<(usize, usize)> {
+    'offset: for offset in 0..pos.y {
+        let jet_index1 = occupied.get(Pos::new(shape[0].x + pos.x, shape[0].y + pos.y - offset));
+        let jet_index2 = occupied.get(Pos::new(
+            shape[0].x + pos.x,
+            shape[0].y + pos.y - (offset * 2),
+        ));
+        for p in shape.iter() {
+            let p1 = Pos::new(p.x + pos.x, p.y + pos.y - offset);
+            let p2 = Pos::new(p.x + pos.x, p.y + pos.y - (offset * 2));
+            if !occupied.contains(p1)
+                || !occupied.contains(p2)
+                || occupied[p1].1 != jet_index1.unwrap().1
+                || occupied[p2].1 != jet_index2.unwrap().1
+                || occupied[p2].1 != jet_index1.unwrap().1
+            {
+                continue 'offset;
+            }
+        }
+        return Some((
+            offset as usize,
+            occupied[Pos::new(shape[0].x + pos.x, shape[0].y + pos.y - offset)].0,
+        ));
+    }
+    None
+}
+
+fn height(rock_count: usize, jet_pattern: &[Dir]) -> usize {
+    let shapes = [
+        vec![
+            //  0123
+            // 0████
+            Pos::new(0, 0),
+            Pos::new(1, 0),
+            Pos::new(2, 0),
+            Pos::new(3, 0),
+        ],
+        vec![
+            //  012
+            // 0 █
+            // 1███
+            // 2 █
+            Pos::new(1, 0),
+            Pos::new(0, 1),
+            Pos::new(1, 1),
+            Pos::new(2, 1),
+            Pos::new(1, 2),
+        ],
+        vec![
+            //  012
+            // 0  █
+            // 1  █
+            // 2███
+            Pos::new(0, 0),
+            Pos::new(1, 0),
+            Pos::new(2, 0),
+            Pos::new(2, 1),
+            Pos::new(2, 2),
+        ],
+        vec![
+            //  0
+            // 0█
+            // 1█
+            // 2█
+            // 3█
+            Pos::new(0, 0),
+            Pos::new(0, 1),
+            Pos::new(0, 2),
+            Pos::new(0, 3),
+        ],
+        vec![
+            //  01
+            // 0██
+            // 1██
+            Pos::new(0, 0),
+            Pos::new(1, 0),
+            Pos::new(0, 1),
+            Pos::new(1, 1),
+        ],
+    ];
+
+    let mut occupied = SparseGrid::new();
+    let mut pos = Pos::new(0, 0);
+    let mut height = 0;
+    let mut jet_pattern_index = (0..jet_pattern.len()).cycle().peekable();
+    let mut rock_i = 0;
+    let mut height_addition = 0;
+
+    for shape in shapes.iter().cycle() {
+        pos.x = 2;
+        pos.y = height + 3;
+        loop {
+            let jet = jet_pattern[jet_pattern_index.next().unwrap()];
+            pos += jet;
+            if shape
+                .iter()
+                .map(|&p| p + pos)
+                .any(|p| collides(p, &occupied))
+            {
+                pos += jet.opposite();
+            }
+            pos += FALL;
+            if shape
+                .iter()
+                .map(|&p| p + pos)
+                .any(|p| collides(p, &occupied))
+            {
+                pos -= FALL;
+                if height_addition > 0 {
+                    break;
+                }
+                if let Some((offset, rock_i2)) = repeats(&pos, shape, &occupied) {
+                    let repeat_amount_until_end = (rock_count - rock_i) / (rock_i - rock_i2);
+                    rock_i += (rock_i - rock_i2) * repeat_amount_until_end;
+                    height_addition = offset * repeat_amount_until_end;
+                }
+                break;
+            }
+        }
+        shape.iter().for_each(|&p| {
+            occupied.insert(p + pos, (rock_i, *jet_pattern_index.peek().unwrap()));
+        });
+        height = height.max(pos.y + max_y(shape) + 1);
+        rock_i += 1;
+        if rock_i >= rock_count {
+            break;
+        }
+    }
+
+    height as usize + height_addition
+}
+
+pub struct Day;
+
+impl days::Day for Day {
+    type Input = Vec<Dir>;
+
+    fn part1(input: &Self::Input) -> Answer {
+        height(2022, input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        height(1_000_000_000_000, input).into()
+    }
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        if input.is_empty() {
+            return Err(ParseError::new("there are no jets").at(input));
+        }
+        input
+            .char_indices()
+            .map(|(i, c)| match Dir::from_arrow(c) {
+                Some(dir @ (Dir::Left | Dir::Right)) => Ok(dir),
+                _ => Err(ParseError::new(format!("invalid jet direction `{}`", c))
+                    .at(&input[i..i + c.len_utf8()])),
+            })
+            .collect()
+    }
+}
@@ -1,6 +1,8 @@
 use std::{collections::HashSet, ops::Add, str::FromStr};
 
-use crate::days;
+use crate::days::{self, Answer};
+use crate::error::ParseError;
+use crate::parse;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 struct Pos {
@@ -16,13 +18,10 @@ impl Pos {
 }
 
 impl FromStr for Pos {
-    type Err = ();
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut split = s.split(",");
-        let x = split.next().unwrap().parse().unwrap();
-        let y = split.next().unwrap().parse().unwrap();
-        let z = split.next().unwrap().parse().unwrap();
+        let [x, y, z] = parse::integers_n(s)?;
         Ok(Self::new(x, y, z))
     }
 }
@@ -136,43 +135,34 @@ impl Grid {
 }
 
 impl FromStr for Grid {
-    type Err = ();
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut grid = HashSet::new();
         for line in s.lines() {
-            grid.insert(line.parse().unwrap());
+            grid.insert(line.parse()?);
+        }
+        if grid.is_empty() {
+            return Err(ParseError::new("no cubes in input"));
         }
         Ok(Self { grid })
     }
 }
 
-pub struct Day {
-    day_num: u8,
-}
+pub struct Day;
 
 impl days::Day for Day {
     type Input = Grid;
 
-    fn get_num(&self) -> u8 {
-        self.day_num
-    }
-
-    fn new(day_num: u8) -> Self {
-        Self {
-            day_num
-        }
-    }
-
-    fn part1(&mut self, input: &Self::Input) -> (String, bool) {
-        (input.surface_area().to_string(), true)
+    fn part1(input: &Self::Input) -> Answer {
+        input.surface_area().into()
     }
 
-    fn part2(&mut self, input: &Self::Input) -> (String, bool) {
-        (input.outer_surface_area().to_string(), true)
+    fn part2(input: &Self::Input) -> Answer {
+        input.outer_surface_area().into()
     }
 
-    fn parse_input(&mut self, input: &String) -> Self::Input {
-        input.parse().unwrap()
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.parse()
     }
 }
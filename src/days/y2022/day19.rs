@@ -1,10 +1,11 @@
 use std::{
     collections::{HashMap, VecDeque},
-    num::ParseIntError,
     str::FromStr,
 };
 
-use crate::days;
+use crate::days::{self, Answer};
+use crate::error::ParseError;
+use crate::parse;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum Material {
@@ -105,6 +106,7 @@ struct RobotCost {
 
 #[derive(Debug)]
 pub struct Blueprint {
+    id: usize,
     ore_robot: RobotCost,
     clay_robot: RobotCost,
     obsidian_robot: RobotCost,
@@ -112,53 +114,24 @@ pub struct Blueprint {
 }
 
 impl FromStr for Blueprint {
-    type Err = ParseIntError;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (_, rest) = s.split_once(':').unwrap();
-        let mut segments = rest.trim().split_terminator('.');
-
-        let ore_robot_cost: usize = segments
-            .next()
-            .unwrap()
-            .trim()
-            .trim_start_matches("Each ore robot costs ")
-            .trim_end_matches(" ore")
-            .parse()?;
-
-        let clay_robot_cost: usize = segments
-            .next()
-            .unwrap()
-            .trim()
-            .trim_start_matches("Each clay robot costs ")
-            .trim_end_matches(" ore")
-            .parse()?;
-
-        let (obsidian_robot_ore_cost, obsidian_robot_clay_cost): (usize, usize) = {
-            let (ore, clay) = segments
-                .next()
-                .unwrap()
-                .trim()
-                .trim_start_matches("Each obsidian robot costs ")
-                .trim_end_matches(" clay")
-                .split_once(" ore and ")
-                .unwrap();
-            (ore.parse()?, clay.parse()?)
-        };
-
-        let (geode_robot_ore_cost, geode_robot_obsidian_cost): (usize, usize) = {
-            let (ore, clay) = segments
-                .next()
-                .unwrap()
-                .trim()
-                .trim_start_matches("Each geode robot costs ")
-                .trim_end_matches(" obsidian")
-                .split_once(" ore and ")
-                .unwrap();
-            (ore.parse()?, clay.parse()?)
-        };
+        let costs = parse::template(
+            "Blueprint {}: Each ore robot costs {} ore. Each clay robot costs {} ore. \
+             Each obsidian robot costs {} ore and {} clay. \
+             Each geode robot costs {} ore and {} obsidian.",
+            s,
+        )?;
+        let id = costs.parse(0)?;
+        let ore_robot_cost = costs.parse(1)?;
+        let clay_robot_cost = costs.parse(2)?;
+        let (obsidian_robot_ore_cost, obsidian_robot_clay_cost) =
+            (costs.parse(3)?, costs.parse(4)?);
+        let (geode_robot_ore_cost, geode_robot_obsidian_cost) = (costs.parse(5)?, costs.parse(6)?);
 
         Ok(Blueprint {
+            id,
             ore_robot: RobotCost {
                 ores: ore_robot_cost,
                 clay: 0,
@@ -276,51 +249,29 @@ fn search(factory: &Blueprint, minutes: i32) -> usize {
     *cache.get(&minutes).unwrap()
 }
 
-pub struct Day {
-    day_num: u8,
-}
+pub struct Day;
 
 impl days::Day for Day {
     type Input = Vec<Blueprint>;
 
-    fn get_num(&self) -> u8 {
-        self.day_num
-    }
-
-    fn new(day_num: u8) -> Self {
-        Self {
-            day_num
-        }
-    }
-
-    fn part1(&mut self, input: &Self::Input) -> (String, bool) {
-        (
-            input
-                .iter()
-                .enumerate()
-                .map(|(i, blueprint)| {
-                    let geode_score = search(blueprint, 24);
-                    geode_score * (i + 1)
-                })
-                .sum::<usize>()
-                .to_string(),
-            true,
-        )
+    fn part1(input: &Self::Input) -> Answer {
+        input
+            .iter()
+            .map(|blueprint| search(blueprint, 24) * blueprint.id)
+            .sum::<usize>()
+            .into()
     }
 
-    fn part2(&mut self, input: &Self::Input) -> (String, bool) {
-        (
-            input
-                .iter()
-                .take(3)
-                .map(|blueprint| search(blueprint, 32))
-                .product::<usize>()
-                .to_string(),
-            true,
-        )
+    fn part2(input: &Self::Input) -> Answer {
+        input
+            .iter()
+            .take(3)
+            .map(|blueprint| search(blueprint, 32))
+            .product::<usize>()
+            .into()
     }
 
-    fn parse_input(&mut self, input: &String) -> Self::Input {
-        input.lines().map(|s| s.parse().unwrap()).collect()
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.lines().map(|s| s.parse()).collect()
     }
 }
@@ -0,0 +1,143 @@
+use crate::days::{self, Answer};
+use crate::error::ParseError;
+
+#[derive(Clone, Copy)]
+pub enum Rps {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+impl Rps {
+    fn from_opponent(c: char) -> Option<Self> {
+        match c {
+            'A' => Some(Rps::Rock),
+            'B' => Some(Rps::Paper),
+            'C' => Some(Rps::Scissors),
+            _ => None,
+        }
+    }
+
+    fn from_me(c: char) -> Option<Self> {
+        match c {
+            'X' => Some(Rps::Rock),
+            'Y' => Some(Rps::Paper),
+            'Z' => Some(Rps::Scissors),
+            _ => None,
+        }
+    }
+
+    fn against(self, win: &Win) -> Self {
+        match (self, win) {
+            (Rps::Rock, Win::Me) => Self::Paper,
+            (Rps::Rock, Win::Opponent) => Self::Scissors,
+            (Rps::Paper, Win::Me) => Self::Scissors,
+            (Rps::Paper, Win::Opponent) => Self::Rock,
+            (Rps::Scissors, Win::Me) => Self::Rock,
+            (Rps::Scissors, Win::Opponent) => Self::Paper,
+            (opponent, Win::Draw) => opponent,
+        }
+    }
+
+    fn score(self) -> u32 {
+        match self {
+            Rps::Rock => 1,
+            Rps::Paper => 2,
+            Rps::Scissors => 3,
+        }
+    }
+}
+
+enum Win {
+    Me,
+    Draw,
+    Opponent,
+}
+
+impl Win {
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            'Z' => Some(Win::Me),
+            'Y' => Some(Win::Draw),
+            'X' => Some(Win::Opponent),
+            _ => None,
+        }
+    }
+
+    fn from_rps(me: &Rps, opponent: &Rps) -> Self {
+        match (me, opponent) {
+            (Rps::Rock, Rps::Scissors) => Win::Me,
+            (Rps::Scissors, Rps::Paper) => Win::Me,
+            (Rps::Paper, Rps::Rock) => Win::Me,
+
+            (Rps::Rock, Rps::Paper) => Win::Opponent,
+            (Rps::Paper, Rps::Scissors) => Win::Opponent,
+            (Rps::Scissors, Rps::Rock) => Win::Opponent,
+
+            (Rps::Rock, Rps::Rock) => Win::Draw,
+            (Rps::Paper, Rps::Paper) => Win::Draw,
+            (Rps::Scissors, Rps::Scissors) => Win::Draw,
+        }
+    }
+
+    fn score(&self) -> u32 {
+        match self {
+            Win::Me => 6,
+            Win::Draw => 3,
+            Win::Opponent => 0,
+        }
+    }
+}
+
+pub struct Day;
+
+impl days::Day for Day {
+    type Input = Vec<(Rps, char)>;
+
+    fn part1(input: &Self::Input) -> Answer {
+        let input = input
+            .iter()
+            .map(|(opponent, me)| (*opponent, Rps::from_me(*me).unwrap()))
+            .collect::<Vec<(Rps, Rps)>>();
+        let mut total_score = 0;
+        for (opponent, me) in input.iter() {
+            total_score += Win::from_rps(me, opponent).score() + me.score();
+        }
+        total_score.into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        let input = input
+            .iter()
+            .map(|(opponent, win)| (*opponent, Win::from_char(*win).unwrap()))
+            .collect::<Vec<(Rps, Win)>>();
+        let mut total_score = 0;
+        for (opponent, win) in input.iter() {
+            total_score += opponent.against(win).score() + win.score();
+        }
+        total_score.into()
+    }
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .lines()
+            .map(|x| {
+                if x.len() != 3 || x.as_bytes()[1] != b' ' {
+                    return Err(ParseError::new(format!(
+                        "expected a move and a response like `A Y`, found `{}`",
+                        x
+                    ))
+                    .at(x));
+                }
+                let opponent = Rps::from_opponent(x.as_bytes()[0] as char).ok_or_else(|| {
+                    ParseError::new(format!("invalid opponent move in `{}`", x)).at(&x[..1])
+                })?;
+                let me = x.as_bytes()[2] as char;
+                if !matches!(me, 'X' | 'Y' | 'Z') {
+                    return Err(ParseError::new(format!("invalid response in `{}`", x)).at(&x[2..]));
+                }
+                Ok((opponent, me))
+            })
+            .collect()
+    }
+}
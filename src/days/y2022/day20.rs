@@ -1,6 +1,8 @@
 use std::str::FromStr;
 
-use crate::days;
+use crate::days::{self, Answer};
+use crate::error::ParseError;
+use crate::parse;
 
 #[derive(Clone)]
 pub struct Nums {
@@ -46,46 +48,34 @@ impl Nums {
 }
 
 impl FromStr for Nums {
-    type Err = ();
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut nums = Self::new();
         for line in s.lines() {
-            nums.add(line.parse().unwrap());
+            nums.add(parse::parse(line)?);
+        }
+        if !nums.nums.contains(&0) {
+            return Err(ParseError::new("the file must contain a 0"));
         }
         Ok(nums)
     }
 }
 
-pub struct Day {
-    day_num: u8,
-}
+pub struct Day;
 
 impl days::Day for Day {
     type Input = Nums;
 
-    fn get_num(&self) -> u8 {
-        self.day_num
-    }
-
-    fn new(day_num: u8) -> Self {
-        Self {
-            day_num
-        }
-    }
-
-    fn part1(&mut self, input: &Self::Input) -> (String, bool) {
+    fn part1(input: &Self::Input) -> Answer {
         let mut nums = input.clone();
         for (i, j) in input.nums.iter().enumerate() {
             nums.move_delta(i, j);
         }
-        (
-            (nums.get_by_0(1000) + nums.get_by_0(2000) + nums.get_by_0(3000)).to_string(),
-            true,
-        )
+        (nums.get_by_0(1000) + nums.get_by_0(2000) + nums.get_by_0(3000)).into()
     }
 
-    fn part2(&mut self, input: &Self::Input) -> (String, bool) {
+    fn part2(input: &Self::Input) -> Answer {
         let mut nums = input.clone();
         for i in nums.nums.iter_mut() {
             *i *= 811589153;
@@ -95,13 +85,10 @@ impl days::Day for Day {
                 nums.move_delta(i, &(j * 811589153));
             }
         }
-        (
-            (nums.get_by_0(1000) + nums.get_by_0(2000) + nums.get_by_0(3000)).to_string(),
-            true,
-        )
+        (nums.get_by_0(1000) + nums.get_by_0(2000) + nums.get_by_0(3000)).into()
     }
 
-    fn parse_input(&mut self, input: &String) -> Self::Input {
-        input.parse().unwrap()
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.parse()
     }
 }
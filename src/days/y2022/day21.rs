@@ -1,6 +1,8 @@
 use std::collections::HashMap;
 
-use crate::days;
+use crate::days::{self, Answer};
+use crate::error::ParseError;
+use crate::parse;
 
 pub enum Monkey {
     Eq(String, String),
@@ -145,96 +147,81 @@ impl Monkey {
     }
 }
 
-pub struct Day {
-    day_num: u8,
-}
+pub struct Day;
 
 impl days::Day for Day {
     type Input = HashMap<String, Monkey>;
 
-    fn get_num(&self) -> u8 {
-        self.day_num
-    }
-
-    fn new(day_num: u8) -> Self {
-        Self {
-            day_num,
-        }
-    }
-
-    fn part1(&mut self, input: &Self::Input) -> (String, bool) {
-        (input["root"].get_num(input).to_string(), true)
+    fn part1(input: &Self::Input) -> Answer {
+        input["root"].get_num(input).into()
     }
 
-    fn part2(&mut self, input: &Self::Input) -> (String, bool) {
-        let input = input;
+    fn part2(input: &Self::Input) -> Answer {
         let root = Monkey::Eq(input["root"].left_side(), input["root"].right_side());
 
-        let left = input[&root.left_side()].find(&"root".to_string(), &"humn".to_string(), &input);
-        let right = input[&root.right_side()].find(&"root".to_string(), &"humn".to_string(), &input);
+        let left = input[&root.left_side()].find(&"root".to_string(), &"humn".to_string(), input);
+        let right = input[&root.right_side()].find(&"root".to_string(), &"humn".to_string(), input);
 
         if !left && right {
-            (
-                input[&root.right_side()]
-                    .match_num(
-                        root.right_side(),
-                        input[&root.left_side()].get_num(&input),
-                        &input,
-                    )
-                    .to_string(),
-                true,
-            )
+            input[&root.right_side()]
+                .match_num(
+                    root.right_side(),
+                    input[&root.left_side()].get_num(input),
+                    input,
+                )
+                .into()
         } else if left && !right {
-            (
-                input[&root.left_side()]
-                    .match_num(
-                        root.left_side(),
-                        input[&root.right_side()].get_num(&input),
-                        &input,
-                    )
-                    .to_string(),
-                true,
-            )
+            input[&root.left_side()]
+                .match_num(
+                    root.left_side(),
+                    input[&root.right_side()].get_num(input),
+                    input,
+                )
+                .into()
         } else {
             panic!("No solution found");
         }
     }
 
-    fn parse_input(&mut self, input: &String) -> Self::Input {
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
         let mut map = HashMap::new();
+        let mut references = vec![];
 
         for line in input.lines() {
-            let mut split = line.split(": ");
-            let key = split.next().unwrap().to_string();
-            let value = split.next().unwrap();
-
-            let value = if value.contains(" + ") {
-                let mut split = value.split(" + ");
-                let a = split.next().unwrap().to_string();
-                let b = split.next().unwrap().to_string();
-                Monkey::Add(a, b)
-            } else if value.contains(" - ") {
-                let mut split = value.split(" - ");
-                let a = split.next().unwrap().to_string();
-                let b = split.next().unwrap().to_string();
-                Monkey::Sub(a, b)
-            } else if value.contains(" * ") {
-                let mut split = value.split(" * ");
-                let a = split.next().unwrap().to_string();
-                let b = split.next().unwrap().to_string();
-                Monkey::Mul(a, b)
-            } else if value.contains(" / ") {
-                let mut split = value.split(" / ");
-                let a = split.next().unwrap().to_string();
-                let b = split.next().unwrap().to_string();
-                Monkey::Div(a, b)
+            let (key, value) = line.split_once(": ").ok_or_else(|| {
+                ParseError::new(format!("expected `name: job` in `{}`", line)).at(line)
+            })?;
+
+            let value = if let Some((a, b)) = value.split_once(" + ") {
+                references.extend([a, b]);
+                Monkey::Add(a.to_string(), b.to_string())
+            } else if let Some((a, b)) = value.split_once(" - ") {
+                references.extend([a, b]);
+                Monkey::Sub(a.to_string(), b.to_string())
+            } else if let Some((a, b)) = value.split_once(" * ") {
+                references.extend([a, b]);
+                Monkey::Mul(a.to_string(), b.to_string())
+            } else if let Some((a, b)) = value.split_once(" / ") {
+                references.extend([a, b]);
+                Monkey::Div(a.to_string(), b.to_string())
             } else {
-                Monkey::Num(value.parse().unwrap())
+                Monkey::Num(parse::parse(value)?)
             };
 
-            map.insert(key, value);
+            map.insert(key.to_string(), value);
+        }
+
+        if let Some(name) = references.into_iter().find(|name| !map.contains_key(*name)) {
+            return Err(
+                ParseError::new(format!("job refers to unknown monkey `{}`", name)).at(name),
+            );
+        }
+        for name in ["root", "humn"] {
+            if !map.contains_key(name) {
+                return Err(ParseError::new(format!("missing monkey `{}`", name)));
+            }
         }
 
-        map
+        Ok(map)
     }
 }
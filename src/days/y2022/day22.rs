@@ -1,6 +1,7 @@
-use std::{collections::HashMap, fmt::{Display, Formatter}};
-
-use crate::days;
+use crate::days::{self, Answer};
+use crate::error::ParseError;
+use crate::grid::{Dir, Pos, SparseGrid};
+use crate::parse;
 
 const CUBE_MAP: &str = "                                                  5555555555555555555555555555555555555555555555555566666666666666666666666666666666666666666666666666
                                                   5555555555555555555555555555555555555555555555555566666666666666666666666666666666666666666666666666
@@ -205,7 +206,12 @@ const CUBE_MAP: &str = "                                                  555555
 
 const CHUNK_SIZE: i32 = 50;
 
-const ZERO: u8 = '0' as u8;
+const ZERO: u8 = b'0';
+
+/// The face of the cube a tile of `CUBE_MAP` belongs to, 0 outside of the real input's net.
+fn face(c: char) -> u8 {
+    (c as u8).saturating_sub(ZERO)
+}
 
 pub enum Rotation {
     Left,
@@ -222,95 +228,35 @@ impl From<char> for Rotation {
     }
 }
 
-#[derive(Hash, Eq, PartialEq, Debug, Copy, Clone)]
-enum Direction {
-    Up,
-    Down,
-    Left,
-    Right,
-}
-
-impl Direction {
-    fn into(self) -> i32 {
-        match self {
-            Direction::Right => 0,
-            Direction::Down => 1,
-            Direction::Left => 2,
-            Direction::Up => 3,
-        }
+/// The facing's part of the password.
+fn facing(dir: Dir) -> i32 {
+    match dir {
+        Dir::Right => 0,
+        Dir::Down => 1,
+        Dir::Left => 2,
+        Dir::Up => 3,
     }
-
-    fn rotate(&self, rotation: &Rotation) -> Self {
-        match rotation {
-            Rotation::Left => match self {
-                Direction::Up => Direction::Left,
-                Direction::Down => Direction::Right,
-                Direction::Left => Direction::Down,
-                Direction::Right => Direction::Up,
-            },
-            Rotation::Right => match self {
-                Direction::Up => Direction::Right,
-                Direction::Down => Direction::Left,
-                Direction::Left => Direction::Up,
-                Direction::Right => Direction::Down,
-            },
-        }
-    }
-}
-
-impl Display for Direction {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Direction::Up => write!(f, "^"),
-            Direction::Down => write!(f, "v"),
-            Direction::Left => write!(f, "<"),
-            Direction::Right => write!(f, ">"),
-        }
-    }
-}
-
-#[derive(Hash, Eq, PartialEq, Debug, Copy, Clone)]
-struct Pos {
-    x: i32,
-    y: i32,
 }
 
-impl Pos {
-    fn new(x: i32, y: i32) -> Self {
-        Self { x, y }
-    }
-
-    fn move_dir(&self, dir: &Direction) -> Self {
-        match dir {
-            Direction::Up => Self {
-                x: self.x,
-                y: self.y - 1,
-            },
-            Direction::Down => Self {
-                x: self.x,
-                y: self.y + 1,
-            },
-            Direction::Left => Self {
-                x: self.x - 1,
-                y: self.y,
-            },
-            Direction::Right => Self {
-                x: self.x + 1,
-                y: self.y,
-            },
-        }
+fn rotate(dir: Dir, rotation: &Rotation) -> Dir {
+    match rotation {
+        Rotation::Left => dir.turn_left(),
+        Rotation::Right => dir.turn_right(),
     }
 }
 
 #[derive(Clone)]
-pub struct Grid {
-    map: HashMap<Pos, (bool, u8)>,
+pub struct Board {
+    /// Whether a tile is a wall, and the face of the cube it is on.
+    map: SparseGrid<(bool, u8)>,
     pos: Pos,
-    dir: Direction,
+    dir: Dir,
 }
 
-impl From<&str> for Grid {
-    fn from(s: &str) -> Self {
+impl TryFrom<&str> for Board {
+    type Error = ParseError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
         //         ...#
         //         .#..
         //         #...
@@ -326,75 +272,70 @@ impl From<&str> for Grid {
         // An example of a grid
         // The starting position is the leftmost . in the top row
         // The direction is right
-        let mut map = HashMap::new();
+        let cube = CUBE_MAP.lines().map(str::as_bytes).collect::<Vec<_>>();
+        let face_of = |pos: Pos| {
+            let c = cube
+                .get(pos.y as usize)
+                .and_then(|line| line.get(pos.x as usize));
+            c.map_or(0, |&c| face(c as char))
+        };
         let mut pos = None;
-        let mut y = 0;
-        for (line, line2) in s.lines().zip(CUBE_MAP.lines()) {
-            let mut x = 0;
-            for (c, c2) in line.chars().zip(line2.chars()) {
-                match c {
-                    '#' => {
-                        map.insert(Pos::new(x, y), (true, c2 as u8 - ZERO));
-                    }
-                    '.' => {
-                        map.insert(Pos::new(x, y), (false, c2 as u8 - ZERO));
-                        if pos.is_none() {
-                            pos = Some(Pos::new(x, y));
-                        }
-                    }
-                    _ => (),
-                }
-                x += 1;
+        let map = SparseGrid::parse(s, |tile, c| match c {
+            '#' => Ok(Some((true, face_of(tile)))),
+            '.' => {
+                pos.get_or_insert(tile);
+                Ok(Some((false, face_of(tile))))
             }
-            y += 1;
-        }
-        let dir = Direction::Right;
-        Self {
+            ' ' => Ok(None),
+            _ => Err(ParseError::new(format!("invalid map tile `{}`", c))),
+        })?;
+        let dir = Dir::Right;
+        Ok(Self {
             map,
-            pos: pos.unwrap(),
+            pos: pos.ok_or_else(|| ParseError::new("the map has no open tiles").at(s))?,
             dir,
-        }
+        })
     }
 }
 
-impl Grid {
+impl Board {
     fn move_dir(&mut self) -> bool {
-        let mut pos = self.pos.move_dir(&self.dir);
+        let mut pos = self.pos + self.dir;
 
         // If we went out of bounds, wrap around to the other side
-        if self.map.get(&pos).is_none() {
+        if !self.map.contains(pos) {
             match self.dir {
-                Direction::Up => {
+                Dir::Up => {
                     pos.y = self
                         .map
-                        .keys()
+                        .positions()
                         .filter(|p| p.x == pos.x)
                         .map(|p| p.y)
                         .max()
                         .unwrap();
                 }
-                Direction::Down => {
+                Dir::Down => {
                     pos.y = self
                         .map
-                        .keys()
+                        .positions()
                         .filter(|p| p.x == pos.x)
                         .map(|p| p.y)
                         .min()
                         .unwrap();
                 }
-                Direction::Left => {
+                Dir::Left => {
                     pos.x = self
                         .map
-                        .keys()
+                        .positions()
                         .filter(|p| p.y == pos.y)
                         .map(|p| p.x)
                         .max()
                         .unwrap();
                 }
-                Direction::Right => {
+                Dir::Right => {
                     pos.x = self
                         .map
-                        .keys()
+                        .positions()
                         .filter(|p| p.y == pos.y)
                         .map(|p| p.x)
                         .min()
@@ -402,7 +343,7 @@ impl Grid {
                 }
             }
         }
-        if self.map.get(&pos).unwrap().0 {
+        if self.map[pos].0 {
             true
         } else {
             self.pos = pos;
@@ -413,7 +354,7 @@ impl Grid {
     fn move_input(&mut self, input: &Input) {
         match input {
             Input::Rotation(rotation) => {
-                self.dir = self.dir.rotate(rotation);
+                self.dir = rotate(self.dir, rotation);
             }
             Input::Move(amount) => {
                 for _ in 0..*amount {
@@ -426,7 +367,7 @@ impl Grid {
     }
 
     fn move_dir_3d(&mut self) -> bool {
-        let mut pos = self.pos.move_dir(&self.dir);
+        let mut pos = self.pos + self.dir;
 
         //   5566
         //   5566
@@ -440,105 +381,105 @@ impl Grid {
         // println!("Before Ln {}, Col {}", pos.y + 1, pos.x + 1);
         //        After  Ln {}, Col {}
 
-        let old_dir = self.dir.clone();
-        if self.map.get(&pos).is_none() {
-            match self.map.get(&self.pos).unwrap().1 {
+        let old_dir = self.dir;
+        if !self.map.contains(pos) {
+            match self.map[self.pos].1 {
                 1 => match self.dir {
-                    Direction::Up => unreachable!(),
+                    Dir::Up => unreachable!(),
 
-                    Direction::Down => {
+                    Dir::Down => {
                         pos.x += CHUNK_SIZE * 2;
                         pos.y = 0;
-                        self.dir = Direction::Down;
+                        self.dir = Dir::Down;
                     }
-                    Direction::Left => {
+                    Dir::Left => {
                         pos.x = pos.y - CHUNK_SIZE * 2;
                         pos.y = 0;
-                        self.dir = Direction::Down;
+                        self.dir = Dir::Down;
                     }
-                    Direction::Right => {
+                    Dir::Right => {
                         pos.x = pos.y - CHUNK_SIZE * 2;
                         pos.y = CHUNK_SIZE * 3 - 1;
-                        self.dir = Direction::Up;
+                        self.dir = Dir::Up;
                     }
                 },
                 2 => match self.dir {
-                    Direction::Down => unreachable!(),
-                    Direction::Right => unreachable!(),
+                    Dir::Down => unreachable!(),
+                    Dir::Right => unreachable!(),
 
-                    Direction::Up => {
+                    Dir::Up => {
                         pos.y = CHUNK_SIZE + pos.x;
                         pos.x = CHUNK_SIZE;
-                        self.dir = Direction::Right;
+                        self.dir = Dir::Right;
                     }
-                    Direction::Left => {
+                    Dir::Left => {
                         pos.y = (CHUNK_SIZE - 1) - (pos.y - CHUNK_SIZE * 2);
                         pos.x = CHUNK_SIZE;
-                        self.dir = Direction::Right;
+                        self.dir = Dir::Right;
                     }
                 },
                 3 => match self.dir {
-                    Direction::Up => unreachable!(),
-                    Direction::Left => unreachable!(),
+                    Dir::Up => unreachable!(),
+                    Dir::Left => unreachable!(),
 
-                    Direction::Down => {
+                    Dir::Down => {
                         pos.y = CHUNK_SIZE * 2 + pos.x;
                         pos.x = CHUNK_SIZE - 1;
-                        self.dir = Direction::Left;
+                        self.dir = Dir::Left;
                     }
-                    Direction::Right => {
+                    Dir::Right => {
                         pos.y = (CHUNK_SIZE - 1) - (pos.y - CHUNK_SIZE * 2);
                         pos.x = CHUNK_SIZE * 3 - 1;
-                        self.dir = Direction::Left;
+                        self.dir = Dir::Left;
                     }
                 },
                 4 => match self.dir {
-                    Direction::Up => unreachable!(),
-                    Direction::Down => unreachable!(),
+                    Dir::Up => unreachable!(),
+                    Dir::Down => unreachable!(),
 
-                    Direction::Left => {
+                    Dir::Left => {
                         pos.x = pos.y - CHUNK_SIZE;
                         pos.y = CHUNK_SIZE * 2;
-                        self.dir = Direction::Down;
+                        self.dir = Dir::Down;
                     }
-                    Direction::Right => {
+                    Dir::Right => {
                         pos.x = CHUNK_SIZE + pos.y;
                         pos.y = CHUNK_SIZE - 1;
-                        self.dir = Direction::Up;
+                        self.dir = Dir::Up;
                     }
                 },
                 5 => match self.dir {
-                    Direction::Right => unreachable!(),
-                    Direction::Down => unreachable!(),
+                    Dir::Right => unreachable!(),
+                    Dir::Down => unreachable!(),
 
-                    Direction::Up => {
+                    Dir::Up => {
                         pos.y = CHUNK_SIZE * 2 + pos.x;
                         pos.x = 0;
-                        self.dir = Direction::Right;
+                        self.dir = Dir::Right;
                     }
-                    Direction::Left => {
+                    Dir::Left => {
                         pos.y = (CHUNK_SIZE - 1) - pos.y + CHUNK_SIZE * 2;
                         pos.x = 0;
-                        self.dir = Direction::Right;
+                        self.dir = Dir::Right;
                     }
                 },
                 6 => match self.dir {
-                    Direction::Left => unreachable!(),
+                    Dir::Left => unreachable!(),
 
-                    Direction::Up => {
+                    Dir::Up => {
                         pos.x -= CHUNK_SIZE * 2;
                         pos.y = CHUNK_SIZE * 4 - 1;
-                        self.dir = Direction::Up;
+                        self.dir = Dir::Up;
                     }
-                    Direction::Down => {
+                    Dir::Down => {
                         pos.y = pos.x - CHUNK_SIZE;
                         pos.x = CHUNK_SIZE * 2 - 1;
-                        self.dir = Direction::Left;
+                        self.dir = Dir::Left;
                     }
-                    Direction::Right => {
+                    Dir::Right => {
                         pos.y = (CHUNK_SIZE - 1) - pos.y + CHUNK_SIZE * 2;
                         pos.x = CHUNK_SIZE * 2 - 1;
-                        self.dir = Direction::Left;
+                        self.dir = Dir::Left;
                     }
                 },
                 _ => unreachable!(),
@@ -547,7 +488,7 @@ impl Grid {
 
         //        Before Ln {}, Col {}
         // println!("After  Ln {}, Col {}", pos.y + 1, pos.x + 1);
-        if self.map.get(&pos).unwrap().0 {
+        if self.map[pos].0 {
             self.dir = old_dir;
             true
         } else {
@@ -559,7 +500,7 @@ impl Grid {
     fn move_input_3d(&mut self, input: &Input) {
         match input {
             Input::Rotation(rotation) => {
-                self.dir = self.dir.rotate(rotation);
+                self.dir = rotate(self.dir, rotation);
             }
             Input::Move(amount) => {
                 for _ in 0..*amount {
@@ -572,7 +513,7 @@ impl Grid {
     }
 
     fn final_password(&self) -> i32 {
-        1000 * (self.pos.y + 1) + 4 * (self.pos.x + 1) + self.dir.into()
+        1000 * (self.pos.y + 1) + 4 * (self.pos.x + 1) + facing(self.dir)
     }
 }
 
@@ -581,75 +522,63 @@ pub enum Input {
     Move(u8),
 }
 
-impl From<&str> for Input {
-    fn from(s: &str) -> Self {
+impl TryFrom<&str> for Input {
+    type Error = ParseError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
         match s {
-            "L" => Self::Rotation(Rotation::Left),
-            "R" => Self::Rotation(Rotation::Right),
-            _ => Self::Move(s.parse().unwrap()),
+            "L" => Ok(Self::Rotation(Rotation::Left)),
+            "R" => Ok(Self::Rotation(Rotation::Right)),
+            _ => Ok(Self::Move(s.parse()?)),
         }
     }
 }
 
-pub struct Day {
-    day_num: u8,
-}
+pub struct Day;
 
 impl days::Day for Day {
-    type Input = (Grid, Vec<Input>);
-
-    fn get_num(&self) -> u8 {
-        self.day_num
-    }
+    type Input = (Board, Vec<Input>);
 
-    fn new(day_num: u8) -> Self {
-        Self { day_num }
-    }
-
-    fn part1(&mut self, input: &Self::Input) -> (String, bool) {
-        let mut grid = input.0.clone();
+    fn part1(input: &Self::Input) -> Answer {
+        let mut board = input.0.clone();
         for input in input.1.iter() {
-            grid.move_input(input);
+            board.move_input(input);
         }
-        (grid.final_password().to_string(), true)
+        board.final_password().into()
     }
 
-    fn part2(&mut self, input: &Self::Input) -> (String, bool) {
-        let mut grid = input.0.clone();
+    fn part2(input: &Self::Input) -> Answer {
+        let mut board = input.0.clone();
         for input in input.1.iter() {
-            grid.move_input_3d(input);
+            board.move_input_3d(input);
         }
-        // grid.print();
-        (grid.final_password().to_string(), true)
+        // board.print();
+        board.final_password().into()
     }
 
-    fn parse_input(&mut self, input: &String) -> Self::Input {
-        let mut split = input.split("\n\n");
-        let map = split.next().unwrap();
-        let inputs_str = split.next().unwrap();
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let (map, inputs_str) = input.split_once("\n\n").ok_or_else(|| {
+            ParseError::new("expected a map and a path separated by an empty line")
+                .at(&input[input.len()..])
+        })?;
         // Parse the inputs
         // Some examples:
         // 10R5L5R
         // 10L4R5L5
         let mut inputs = vec![];
-        let mut current = String::new();
-        for c in inputs_str.chars() {
-            match c {
-                'L' | 'R' => {
-                    if !current.is_empty() {
-                        inputs.push(Input::Move(current.parse().unwrap()));
-                        current = String::new();
-                    }
-                    inputs.push(Input::Rotation(Rotation::from(c)));
-                }
-                _ => {
-                    current.push(c);
+        let mut start = 0;
+        for (i, c) in inputs_str.char_indices() {
+            if let 'L' | 'R' = c {
+                if start < i {
+                    inputs.push(Input::Move(parse::parse(&inputs_str[start..i])?));
                 }
+                inputs.push(Input::Rotation(Rotation::from(c)));
+                start = i + 1;
             }
         }
-        if !current.is_empty() {
-            inputs.push(Input::Move(current.parse().unwrap()));
+        if start < inputs_str.len() {
+            inputs.push(Input::Move(parse::parse(&inputs_str[start..])?));
         }
-        (Grid::from(map), inputs)
+        Ok((Board::try_from(map)?, inputs))
     }
 }
@@ -0,0 +1,141 @@
+use std::str::FromStr;
+
+use crate::days::{self, Answer};
+use crate::error::ParseError;
+use crate::grid::{Pos, SparseGrid};
+
+#[derive(Clone)]
+pub struct Elves {
+    grid: SparseGrid<()>,
+    proposed_pos: Vec<(Pos, Pos)>,
+    directions: Vec<[Pos; 3]>,
+}
+
+impl Elves {
+    fn new() -> Self {
+        Self {
+            grid: SparseGrid::new(),
+            proposed_pos: Vec::new(),
+            directions: vec![
+                [Pos::new(-1, -1), Pos::new(0, -1), Pos::new(1, -1)],
+                [Pos::new(-1, 1), Pos::new(0, 1), Pos::new(1, 1)],
+                [Pos::new(-1, 1), Pos::new(-1, 0), Pos::new(-1, -1)],
+                [Pos::new(1, 1), Pos::new(1, 0), Pos::new(1, -1)],
+            ],
+        }
+    }
+
+    fn contains(&self, pos: Pos) -> bool {
+        self.grid.contains(pos)
+    }
+
+    fn get_neighbours(&self, pos: Pos) -> usize {
+        pos.neighbours8().filter(|&p| self.contains(p)).count()
+    }
+
+    fn count_empty(&self) -> usize {
+        let (min, max) = self.grid.bounds().unwrap();
+        let area = (max.x - min.x + 1) as usize * (max.y - min.y + 1) as usize;
+        area - self.grid.len()
+    }
+
+    fn proposed_positions(&self, pos: Pos) -> Option<Pos> {
+        if self.get_neighbours(pos) == 0 {
+            return None;
+        }
+        for dir in self.directions.iter() {
+            if !dir.iter().map(|&d| pos + d).any(|p| self.contains(p)) {
+                return Some(pos + dir[1]);
+            }
+        }
+        None
+    }
+
+    fn propose_positions(&mut self) {
+        self.proposed_pos.clear();
+        for pos in self.grid.positions() {
+            if let Some(new_pos) = self.proposed_positions(pos) {
+                self.proposed_pos.push((pos, new_pos));
+            } else {
+                self.proposed_pos.push((pos, pos));
+            }
+        }
+    }
+
+    fn apply_proposed_positions(&mut self) -> bool {
+        let mut new_grid = SparseGrid::new();
+        let mut changed = false;
+        for i in 0..self.proposed_pos.len() {
+            let (old_pos, new_pos) = self.proposed_pos[i];
+            if self
+                .proposed_pos
+                .iter()
+                .any(|(o, p)| o != &old_pos && p == &new_pos)
+            {
+                new_grid.insert(old_pos, ());
+            } else {
+                new_grid.insert(new_pos, ());
+                if old_pos != new_pos {
+                    changed = true;
+                }
+            }
+        }
+        self.grid = new_grid;
+        changed
+    }
+}
+
+impl FromStr for Elves {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut elves = Elves::new();
+        elves.grid = SparseGrid::parse(s, |_, c| match c {
+            '#' => Ok(Some(())),
+            '.' => Ok(None),
+            _ => Err(ParseError::new(format!("invalid tile `{}`", c))),
+        })?;
+        if elves.grid.is_empty() {
+            return Err(ParseError::new("there are no elves in the input").at(s));
+        }
+        Ok(elves)
+    }
+}
+
+pub struct Day;
+
+impl days::Day for Day {
+    type Input = Elves;
+
+    fn part1(input: &Self::Input) -> Answer {
+        let mut elves = input.clone();
+
+        for _ in 0..10 {
+            elves.propose_positions();
+            elves.apply_proposed_positions();
+            elves.directions.rotate_left(1);
+        }
+
+        elves.count_empty().into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        let mut elves = input.clone();
+        let mut round = 0;
+
+        loop {
+            round += 1;
+            elves.propose_positions();
+            if !elves.apply_proposed_positions() {
+                break;
+            }
+            elves.directions.rotate_left(1);
+        }
+
+        round.into()
+    }
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.parse()
+    }
+}
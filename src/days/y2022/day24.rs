@@ -2,48 +2,9 @@ use std::{ops::Index, str::FromStr};
 
 use pathfinding::prelude::astar;
 
-use crate::days;
-
-#[derive(Clone, PartialEq, Eq)]
-enum Direction {
-    UP,
-    DOWN,
-    LEFT,
-    RIGHT,
-}
-
-impl From<char> for Direction {
-    fn from(c: char) -> Self {
-        match c {
-            '^' => Direction::UP,
-            'v' => Direction::DOWN,
-            '<' => Direction::LEFT,
-            '>' => Direction::RIGHT,
-            _ => panic!("Invalid direction: {}", c),
-        }
-    }
-}
-
-#[derive(Clone, PartialEq, Eq)]
-struct Pos {
-    x: i32,
-    y: i32,
-}
-
-impl Pos {
-    fn new(x: i32, y: i32) -> Self {
-        Self { x, y }
-    }
-
-    fn move_in(&mut self, dir: &Direction) {
-        match dir {
-            Direction::UP => self.y -= 1,
-            Direction::DOWN => self.y += 1,
-            Direction::LEFT => self.x -= 1,
-            Direction::RIGHT => self.x += 1,
-        }
-    }
-}
+use crate::days::{self, Answer};
+use crate::error::ParseError;
+use crate::grid::{Dir, Grid, Pos};
 
 #[derive(Debug, Hash, PartialEq, Eq, Clone)]
 struct Pos3D {
@@ -61,16 +22,16 @@ impl Pos3D {
 #[derive(Clone, PartialEq, Eq)]
 struct Blizzard {
     pos: Pos,
-    dir: Direction,
+    dir: Dir,
 }
 
 impl Blizzard {
-    fn new(pos: Pos, dir: Direction) -> Self {
+    fn new(pos: Pos, dir: Dir) -> Self {
         Self { pos, dir }
     }
 
     fn next(&mut self) {
-        self.pos.move_in(&self.dir);
+        self.pos += self.dir;
     }
 }
 
@@ -82,35 +43,57 @@ struct Blizzards {
 }
 
 impl FromStr for Blizzards {
-    type Err = ();
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut lines = s.lines();
 
         // Skip the first and last line
-        let width = lines.next().unwrap().len() as i32 - 2;
+        let width = lines
+            .next()
+            .filter(|line| line.len() > 2)
+            .ok_or_else(|| ParseError::new("the valley is missing its top wall").at(s))?
+            .len() as i32
+            - 2;
         lines.next_back();
 
         let mut blizzards = Vec::new();
         let mut height = 0;
 
         for (y, line) in lines.enumerate() {
-            let mut chars = line.chars();
+            if line.len() as i32 != width + 2 {
+                return Err(ParseError::new(format!(
+                    "the row is {} wide, expected {} like the top wall",
+                    line.len(),
+                    width + 2
+                ))
+                .at(line));
+            }
+
+            let mut chars = line.char_indices();
 
             // Skip the first and last character
             chars.next();
             chars.next_back();
 
-            for (x, c) in chars.enumerate() {
+            for (x, (i, c)) in chars.enumerate() {
                 if c == '.' {
                     continue;
                 }
 
-                blizzards.push(Blizzard::new(Pos::new(x as i32, y as i32), c.into()));
+                let dir = Dir::from_arrow(c).ok_or_else(|| {
+                    ParseError::new(format!("invalid direction: {}", c))
+                        .at(&line[i..i + c.len_utf8()])
+                })?;
+                blizzards.push(Blizzard::new(Pos::new(x as i32, y as i32), dir));
             }
             height += 1;
         }
 
+        if height == 0 {
+            return Err(ParseError::new("the valley has no rows").at(&s[s.len()..]));
+        }
+
         Ok(Self {
             blizzards,
             width,
@@ -131,11 +114,11 @@ impl Blizzards {
     }
 }
 
+/// The same grid at every step of time, repeating.
 struct Vec3D<T> {
-    data: Vec<Vec<Vec<T>>>,
+    data: Vec<Grid<T>>,
     width: i32,
     height: i32,
-    depth: i32,
 }
 
 impl<T> Vec3D<T> {
@@ -144,13 +127,11 @@ impl<T> Vec3D<T> {
             data: Vec::new(),
             width,
             height,
-            depth: 0,
         }
     }
 
-    fn add(&mut self, vec2d: Vec<Vec<T>>) {
-        self.depth += 1;
-        self.data.push(vec2d);
+    fn add(&mut self, layer: Grid<T>) {
+        self.data.push(layer);
     }
 }
 
@@ -158,29 +139,29 @@ impl<T> Index<&Pos3D> for Vec3D<T> {
     type Output = T;
 
     fn index(&self, pos: &Pos3D) -> &Self::Output {
-        &self.data[pos.z.rem_euclid(self.depth) as usize][pos.y as usize][pos.x as usize]
+        &self.data[pos.z.rem_euclid(self.data.len() as i32) as usize][Pos::new(pos.x, pos.y)]
     }
 }
 
-pub struct Grid {
+pub struct Valley {
     accessible: Vec3D<bool>,
 }
 
-impl Grid {
+impl Valley {
     fn new(blizzards: &Blizzards) -> Self {
-        let mut grid = Self {
+        let mut valley = Self {
             accessible: Vec3D::new(blizzards.width, blizzards.height),
         };
-        grid.add(blizzards);
-        grid
+        valley.add(blizzards);
+        valley
     }
 
     fn add(&mut self, blizzards: &Blizzards) {
-        let mut vec2d = vec![vec![true; blizzards.width as usize]; blizzards.height as usize];
+        let mut layer = Grid::new(blizzards.width as usize, blizzards.height as usize, true);
         for blizzard in blizzards.blizzards.iter() {
-            vec2d[blizzard.pos.y as usize][blizzard.pos.x as usize] = false;
+            layer[blizzard.pos] = false;
         }
-        self.accessible.add(vec2d);
+        self.accessible.add(layer);
     }
 
     fn neighbors(&self, pos: &Pos3D) -> Vec<(Pos3D, i32)> {
@@ -230,50 +211,36 @@ impl Grid {
             && pos.x < self.accessible.width
             && pos.y >= 0
             && pos.y < self.accessible.height
+            && self.accessible[&Pos3D::new(pos.x, pos.y, pos.z + 1)]
         {
-            if self.accessible[&Pos3D::new(pos.x, pos.y, pos.z + 1)] {
-                neighbors.push((Pos3D::new(pos.x, pos.y, pos.z + 1), 1));
-            }
+            neighbors.push((Pos3D::new(pos.x, pos.y, pos.z + 1), 1));
         }
 
         neighbors
     }
 }
 
-pub struct Day {
-    day_num: u8,
-}
+pub struct Day;
 
 impl days::Day for Day {
-    type Input = Grid;
-
-    fn get_num(&self) -> u8 {
-        self.day_num
-    }
+    type Input = Valley;
 
-    fn new(day_num: u8) -> Self {
-        Self { day_num }
-    }
-
-    fn part1(&mut self, input: &Self::Input) -> (String, bool) {
-        (
-            astar(
-                &Pos3D::new(0, -1, 0),
-                |p| input.neighbors(p),
-                |p| {
-                    (p.x.abs_diff(input.accessible.width - 1)
-                        + p.y.abs_diff(input.accessible.height)) as i32
-                },
-                |p| p.x == input.accessible.width - 1 && p.y == input.accessible.height,
-            )
-            .unwrap()
-            .1
-            .to_string(),
-            true,
+    fn part1(input: &Self::Input) -> Answer {
+        astar(
+            &Pos3D::new(0, -1, 0),
+            |p| input.neighbors(p),
+            |p| {
+                (p.x.abs_diff(input.accessible.width - 1) + p.y.abs_diff(input.accessible.height))
+                    as i32
+            },
+            |p| p.x == input.accessible.width - 1 && p.y == input.accessible.height,
         )
+        .unwrap()
+        .1
+        .into()
     }
 
-    fn part2(&mut self, input: &Self::Input) -> (String, bool) {
+    fn part2(input: &Self::Input) -> Answer {
         let mut z = 0;
         let first = astar(
             &Pos3D::new(0, -1, z),
@@ -323,20 +290,20 @@ impl days::Day for Day {
         )
         .unwrap()
         .1;
-        ((first + second + third).to_string(), true)
+        (first + second + third).into()
     }
 
-    fn parse_input(&mut self, input: &String) -> Self::Input {
-        let mut blizzards: Blizzards = input.parse().unwrap();
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut blizzards: Blizzards = input.parse()?;
         let original_blizzards = blizzards.clone();
-        let mut grid = Grid::new(&blizzards);
+        let mut valley = Valley::new(&blizzards);
         blizzards.next();
 
         while blizzards != original_blizzards {
-            grid.add(&blizzards);
+            valley.add(&blizzards);
             blizzards.next();
         }
 
-        grid
+        Ok(valley)
     }
 }
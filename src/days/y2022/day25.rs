@@ -0,0 +1,69 @@
+use crate::days::{self, Answer};
+use crate::error::ParseError;
+
+fn snafu_digit_to_i64(digit: char) -> Result<i64, ParseError> {
+    match digit {
+        '=' => Ok(-2),
+        '-' => Ok(-1),
+        '0' => Ok(0),
+        '1' => Ok(1),
+        '2' => Ok(2),
+        _ => Err(ParseError::new(format!(
+            "{} is not a valid SNAFU digit!",
+            digit
+        ))),
+    }
+}
+
+fn snafu_to_i64(num: &str) -> Result<i64, ParseError> {
+    let mut result = vec![];
+    let mut multiple = 1;
+    for (i, digit) in num.char_indices().rev() {
+        let digit =
+            snafu_digit_to_i64(digit).map_err(|err| err.at(&num[i..i + digit.len_utf8()]))?;
+        result.push(digit * multiple);
+        multiple *= 5;
+    }
+    Ok(result.iter().sum())
+}
+
+fn i64_digit_to_snafu(digit: i64) -> String {
+    match digit {
+        -2 => "=".to_string(),
+        -1 => "-".to_string(),
+        0 => "0".to_string(),
+        1 => "1".to_string(),
+        2 => "2".to_string(),
+        _ => panic!("{} is not a valid SNAFU digit!", digit),
+    }
+}
+
+fn i64_to_snafu(num: i64) -> String {
+    if num == 0 {
+        "".to_string()
+    } else {
+        match num.rem_euclid(5) {
+            0..=2 => i64_to_snafu(num / 5) + &i64_digit_to_snafu(num.rem_euclid(5)),
+            3 | 4 => i64_to_snafu(num / 5 + 1) + &i64_digit_to_snafu(num.rem_euclid(5) - 5),
+            _ => panic!("How did you get here?!"),
+        }
+    }
+}
+
+pub struct Day;
+
+impl days::Day for Day {
+    type Input = Vec<i64>;
+
+    fn part1(input: &Self::Input) -> Answer {
+        i64_to_snafu(input.iter().sum()).into()
+    }
+
+    fn part2(_input: &Self::Input) -> Answer {
+        Answer::NotApplicable
+    }
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.lines().map(snafu_to_i64).collect()
+    }
+}
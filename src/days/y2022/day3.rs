@@ -1,4 +1,5 @@
-use crate::days;
+use crate::days::{self, Answer};
+use crate::error::ParseError;
 
 fn get_priority(c: char) -> u32 {
     match c {
@@ -59,24 +60,12 @@ fn get_priority(c: char) -> u32 {
     }
 }
 
-pub struct Day {
-    day_num: u8,
-}
+pub struct Day;
 
 impl days::Day for Day {
     type Input = Vec<String>;
 
-    fn get_num(&self) -> u8 {
-        self.day_num
-    }
-
-    fn new(day_num: u8) -> Self {
-        Self {
-            day_num
-        }
-    }
-
-    fn part1(&mut self, input: &Self::Input) -> (String, bool) {
+    fn part1(input: &Self::Input) -> Answer {
         let rucksacks = input
             .iter()
             .map(|s| {
@@ -93,10 +82,10 @@ impl days::Day for Day {
                 }
             }
         }
-        (priority.to_string(), true)
+        priority.into()
     }
 
-    fn part2(&mut self, input: &Self::Input) -> (String, bool) {
+    fn part2(input: &Self::Input) -> Answer {
         let rucksack_groups = input.chunks(3).collect::<Vec<&[String]>>();
         let mut priority = 0;
         for group in rucksack_groups {
@@ -107,10 +96,34 @@ impl days::Day for Day {
                 }
             }
         }
-        (priority.to_string(), true)
+        priority.into()
     }
 
-    fn parse_input(&mut self, input: &String) -> Self::Input {
-        input.lines().map(|s| s.to_string()).collect()
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let rucksacks = input
+            .lines()
+            .map(|s| {
+                if let Some((i, c)) = s.char_indices().find(|(_, c)| !c.is_ascii_alphabetic()) {
+                    return Err(ParseError::new(format!("invalid item `{}`", c))
+                        .at(&s[i..i + c.len_utf8()]));
+                }
+                if s.len() % 2 != 0 {
+                    return Err(ParseError::new(format!(
+                        "a rucksack has two compartments of the same size, but it has {} items",
+                        s.len()
+                    ))
+                    .at(s));
+                }
+                Ok(s.to_string())
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if rucksacks.len() % 3 != 0 {
+            return Err(ParseError::new(format!(
+                "the elves are in groups of 3, but there are {} rucksacks",
+                rucksacks.len()
+            ))
+            .at(&input[input.len()..]));
+        }
+        Ok(rucksacks)
     }
 }
@@ -0,0 +1,54 @@
+use crate::days::{self, Answer};
+use crate::error::ParseError;
+use crate::parse;
+
+fn contains_other(a: &(i32, i32), b: &(i32, i32)) -> bool {
+    a.0 <= b.0 && a.1 >= b.1
+}
+
+fn any_contains_other(a: &(i32, i32), b: &(i32, i32)) -> bool {
+    contains_other(a, b) || contains_other(b, a)
+}
+
+fn overlaps(a: &(i32, i32), b: &(i32, i32)) -> bool {
+    a.0 <= b.0 && a.1 >= b.0 || b.0 <= a.0 && b.1 >= a.0
+}
+
+pub struct Day;
+
+impl days::Day for Day {
+    type Input = Vec<((i32, i32), (i32, i32))>;
+
+    fn part1(input: &Self::Input) -> Answer {
+        let mut contained = 0;
+        for (a, b) in input.iter() {
+            if any_contains_other(a, b) {
+                contained += 1;
+            }
+        }
+        contained.into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        let mut overlapped = 0;
+        for (a, b) in input.iter() {
+            if overlaps(a, b) {
+                overlapped += 1;
+            }
+        }
+        overlapped.into()
+    }
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .lines()
+            .map(|line| {
+                let fields = parse::template("{}-{},{}-{}", line)?;
+                Ok((
+                    (fields.parse(0)?, fields.parse(1)?),
+                    (fields.parse(2)?, fields.parse(3)?),
+                ))
+            })
+            .collect()
+    }
+}
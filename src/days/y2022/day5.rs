@@ -0,0 +1,85 @@
+use crate::days::{self, Answer};
+use crate::error::ParseError;
+use crate::parse;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Move {
+    amount: usize,
+    /// Index of the stack, from 0.
+    from: usize,
+    to: usize,
+}
+
+fn tops(crates: &[Vec<char>]) -> String {
+    crates.iter().filter_map(|stack| stack.last()).collect()
+}
+
+pub struct Day;
+
+impl days::Day for Day {
+    /// The stacks from bottom to top, and the moves.
+    type Input = (Vec<Vec<char>>, Vec<Move>);
+
+    fn part1(input: &Self::Input) -> Answer {
+        let mut crates = input.0.clone();
+        for &Move { amount, from, to } in input.1.iter() {
+            for _ in 0..amount {
+                let elem = crates[from].pop().unwrap();
+                crates[to].push(elem);
+            }
+        }
+        tops(&crates).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        let mut crates = input.0.clone();
+        for &Move { amount, from, to } in input.1.iter() {
+            let lifted = crates[from].len() - amount;
+            let tmp = crates[from].split_off(lifted);
+            crates[to].extend(tmp);
+        }
+        tops(&crates).into()
+    }
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let (drawing, moves) = parse::split_once(input, "\n\n")?;
+
+        let mut rows = drawing.lines().rev();
+        let numbers = rows.next().unwrap_or_default();
+        let mut crates = vec![vec![]; numbers.split_whitespace().count()];
+        for row in rows {
+            for (i, (offset, c)) in row.char_indices().skip(1).step_by(4).enumerate() {
+                if c == ' ' {
+                    continue;
+                }
+                let stack = crates.get_mut(i).ok_or_else(|| {
+                    ParseError::new(format!("crate `{}` is not above a stack", c))
+                        .at(&row[offset..offset + c.len_utf8()])
+                })?;
+                stack.push(c);
+            }
+        }
+
+        let moves = moves
+            .lines()
+            .map(|line| {
+                let fields = parse::template("move {} from {} to {}", line)?;
+                let stack = |index| {
+                    let stack = fields.parse::<usize>(index)?;
+                    if (1..=crates.len()).contains(&stack) {
+                        Ok(stack - 1)
+                    } else {
+                        Err(ParseError::new(format!("there is no stack {}", stack))
+                            .at(fields.str(index)))
+                    }
+                };
+                Ok(Move {
+                    amount: fields.parse(0)?,
+                    from: stack(1)?,
+                    to: stack(2)?,
+                })
+            })
+            .collect::<Result<_, ParseError>>()?;
+        Ok((crates, moves))
+    }
+}
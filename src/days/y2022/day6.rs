@@ -0,0 +1,55 @@
+use crate::days::{self, Answer};
+use crate::error::ParseError;
+
+pub struct Day;
+
+impl days::Day for Day {
+    type Input = String;
+
+    fn part1(input: &Self::Input) -> Answer {
+        for i in 0..input.len() - 4 {
+            let mut group = String::new();
+            for j in input.get(i..i + 4).unwrap().chars() {
+                if group.contains(j) {
+                    break;
+                }
+                group.push(j);
+                if group.len() == 4 {
+                    return (i + 4).into();
+                }
+            }
+        }
+        Answer::NotApplicable
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        for i in 0..input.len() - 14 {
+            let mut group = String::new();
+            for j in input.get(i..i + 14).unwrap().chars() {
+                if group.contains(j) {
+                    break;
+                }
+                group.push(j);
+                if group.len() == 14 {
+                    return (i + 14).into();
+                }
+            }
+        }
+        Answer::NotApplicable
+    }
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        if let Some((i, c)) = input.char_indices().find(|(_, c)| !c.is_ascii_lowercase()) {
+            return Err(ParseError::new(format!("invalid character `{}`", c))
+                .at(&input[i..i + c.len_utf8()]));
+        }
+        if input.len() < 14 {
+            return Err(ParseError::new(format!(
+                "the datastream is {} characters long, a message marker takes 14",
+                input.len()
+            ))
+            .at(input));
+        }
+        Ok(input.to_string())
+    }
+}
@@ -0,0 +1,119 @@
+use std::collections::HashMap;
+
+use crate::days::{self, Answer};
+use crate::error::ParseError;
+use crate::parse;
+
+/// The path of `name` in the directory `dir`.
+fn child_path(dir: &str, name: &str) -> String {
+    if dir.ends_with('/') {
+        format!("{}{}", dir, name)
+    } else {
+        format!("{}/{}", dir, name)
+    }
+}
+
+fn get_size(
+    search_dir: String,
+    input: &HashMap<String, Vec<(u32, String)>>,
+    sizes: &mut HashMap<String, u32>,
+) -> u32 {
+    let mut size = 0;
+    for (num, dir) in input.get(&search_dir).unwrap() {
+        if num == &0 {
+            size += get_size(child_path(&search_dir, dir), input, sizes);
+        } else {
+            size += num;
+        }
+    }
+    sizes.insert(search_dir, size);
+    size
+}
+
+pub struct Day;
+
+impl days::Day for Day {
+    type Input = HashMap<String, Vec<(u32, String)>>;
+
+    fn part1(input: &Self::Input) -> Answer {
+        let mut sizes = HashMap::new();
+        get_size("/".to_string(), input, &mut sizes);
+        sizes
+            .values()
+            .filter(|&&size| size < 100000)
+            .sum::<u32>()
+            .into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        let mut sizes = HashMap::new();
+        let unused = 70000000u32.saturating_sub(get_size("/".to_string(), input, &mut sizes));
+        let delete_size = 30000000u32.saturating_sub(unused);
+        sizes
+            .iter()
+            .map(|x| *x.1)
+            .filter(|&x| x >= delete_size)
+            .min()
+            .unwrap()
+            .into()
+    }
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut map: Self::Input = HashMap::new();
+        let mut curr_dir = String::new();
+        let mut subdirs = vec![];
+        for line in input.lines() {
+            if line.starts_with('$') {
+                let mut words = line.split_whitespace();
+                words.next();
+                let command = words
+                    .next()
+                    .ok_or_else(|| ParseError::new(format!("missing command in `{}`", line)))?;
+
+                if command == "ls" {
+                    map.entry(curr_dir.clone()).or_default();
+                } else if command == "cd" {
+                    let dir = words.next().ok_or_else(|| {
+                        ParseError::new(format!("missing directory in `{}`", line))
+                    })?;
+                    if dir == ".." {
+                        curr_dir = curr_dir
+                            .split('/')
+                            .take(curr_dir.split('/').count() - 1)
+                            .collect::<Vec<&str>>()
+                            .join("/");
+                    } else {
+                        if !curr_dir.ends_with("/") && !dir.starts_with("/") {
+                            curr_dir += "/";
+                        }
+                        curr_dir += dir;
+                    }
+                }
+            } else {
+                let mut words = line.split_whitespace();
+                let num = match words.next() {
+                    Some("dir") => 0,
+                    Some(num) => parse::parse(num)?,
+                    None => return Err(ParseError::new("empty line in `ls` output")),
+                };
+                let dir = words
+                    .next()
+                    .ok_or_else(|| ParseError::new(format!("missing name in `{}`", line)))?;
+                if num == 0 {
+                    subdirs.push((child_path(&curr_dir, dir), line));
+                }
+                map.entry(curr_dir.clone())
+                    .or_default()
+                    .push((num, dir.to_string()));
+            }
+        }
+        // Every directory has to be listed to know its size.
+        if !map.contains_key("/") {
+            return Err(ParseError::new("`/` is never listed").at(&input[input.len()..]));
+        }
+        if let Some((path, line)) = subdirs.iter().find(|(path, _)| !map.contains_key(path)) {
+            return Err(ParseError::new(format!("`{}` is never listed", path)).at(line));
+        }
+        Ok(map)
+    }
+}
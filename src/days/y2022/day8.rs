@@ -0,0 +1,60 @@
+use crate::days::{self, Answer};
+use crate::error::ParseError;
+use crate::grid::{Dir, Grid, Pos};
+
+fn is_visible(pos: Pos, input: &Grid<u8>) -> bool {
+    let height = input[pos];
+    Dir::ALL
+        .into_iter()
+        .any(|dir| input.ray(pos, dir).all(|&tree| tree < height))
+}
+
+fn viewing_distance<'a>(height: u8, trees: impl Iterator<Item = &'a u8>) -> u32 {
+    let mut distance = 0;
+    for &tree in trees {
+        distance += 1;
+        if tree >= height {
+            break;
+        }
+    }
+    distance
+}
+
+fn scenic_score(pos: Pos, input: &Grid<u8>) -> u32 {
+    let height = input[pos];
+    Dir::ALL
+        .into_iter()
+        .map(|dir| viewing_distance(height, input.ray(pos, dir)))
+        .product()
+}
+
+pub struct Day;
+
+impl days::Day for Day {
+    type Input = Grid<u8>;
+
+    fn part1(input: &Self::Input) -> Answer {
+        input
+            .positions()
+            .filter(|&pos| is_visible(pos, input))
+            .count()
+            .into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        input
+            .positions()
+            .map(|pos| scenic_score(pos, input))
+            .max()
+            .unwrap_or(0)
+            .into()
+    }
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Grid::parse(input, |_, x| {
+            x.to_digit(10)
+                .map(|height| height as u8)
+                .ok_or_else(|| ParseError::new(format!("invalid tree height `{}`", x)))
+        })
+    }
+}
@@ -1,6 +1,8 @@
 use std::collections::HashSet;
 
-use crate::days;
+use crate::days::{self, Answer};
+use crate::error::ParseError;
+use crate::parse;
 
 #[derive(Debug)]
 pub enum Direction {
@@ -47,7 +49,7 @@ impl Position {
     }
 
     fn follow(&mut self, other: &Position) {
-        if self.is_touching(&other) {
+        if self.is_touching(other) {
             return;
         }
         if self.x == other.x {
@@ -77,24 +79,12 @@ impl Position {
     }
 }
 
-pub struct Day {
-    day_num: u8,
-}
+pub struct Day;
 
 impl days::Day for Day {
     type Input = Vec<(Direction, u32)>;
 
-    fn get_num(&self) -> u8 {
-        self.day_num
-    }
-
-    fn new(day_num: u8) -> Self {
-        Self {
-            day_num
-        }
-    }
-
-    fn part1(&mut self, input: &Self::Input) -> (String, bool) {
+    fn part1(input: &Self::Input) -> Answer {
         let mut head = Position::new();
         let mut tail = Position::new();
         let mut tail_path = HashSet::new();
@@ -107,10 +97,10 @@ impl days::Day for Day {
             }
         }
 
-        (tail_path.len().to_string(), true)
+        tail_path.len().into()
     }
 
-    fn part2(&mut self, input: &Self::Input) -> (String, bool) {
+    fn part2(input: &Self::Input) -> Answer {
         let mut head = Position::new();
         let mut tail1 = Position::new();
         let mut tail2 = Position::new();
@@ -139,15 +129,24 @@ impl days::Day for Day {
             }
         }
 
-        (tail_path.len().to_string(), true)
+        tail_path.len().into()
     }
 
-    fn parse_input(&mut self, input: &String) -> Self::Input {
-        input.lines().map(|x| {
-            let mut split = x.split(" ");
-            let dir = Direction::from_char(split.next().unwrap().chars().next().unwrap()).unwrap();
-            let dist = split.next().unwrap().parse::<u32>().unwrap();
-            (dir, dist)
-        }).collect()
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .lines()
+            .map(|x| {
+                let (dir, dist) = x.split_once(' ').ok_or_else(|| {
+                    ParseError::new(format!("expected a direction and a distance in `{}`", x))
+                })?;
+                let dir = dir
+                    .chars()
+                    .next()
+                    .and_then(Direction::from_char)
+                    .ok_or_else(|| ParseError::new(format!("invalid direction `{}`", dir)))?;
+                let dist = parse::parse::<u32>(dist)?;
+                Ok((dir, dist))
+            })
+            .collect()
     }
 }
@@ -0,0 +1,176 @@
+use std::{
+    fmt::{Display, Formatter},
+    num::ParseIntError,
+};
+
+/// Why a day's `parse` rejected its input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    message: String,
+    /// The address and length of the text the error is about, see [`ParseError::at`].
+    span: Option<(usize, usize)>,
+    location: Option<Location>,
+}
+
+/// Where in the input a [`ParseError`] is, once it's been [located](ParseError::locate).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Location {
+    /// Counted from 1.
+    pub line: usize,
+    /// Counted from 1, in characters.
+    pub column: usize,
+    /// How many characters of the line the error is about, at least 1.
+    pub width: usize,
+    /// The whole line.
+    pub text: String,
+}
+
+impl ParseError {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            span: None,
+            location: None,
+        }
+    }
+
+    /// Points the error at `text`, a slice of the input. An error already pointed somewhere keeps
+    /// it, the innermost place is the most precise one.
+    pub fn at(mut self, text: &str) -> Self {
+        if self.span.is_none() {
+            self.span = Some((text.as_ptr() as usize, text.len()));
+        }
+        self
+    }
+
+    /// Finds the line and column of `input` the error points at. Does nothing if it doesn't
+    /// point at a slice of `input`, like a copy of it.
+    pub fn locate(mut self, input: &str) -> Self {
+        let Some((address, len)) = self.span else {
+            return self;
+        };
+        let Some(offset) = address.checked_sub(input.as_ptr() as usize) else {
+            return self;
+        };
+        if offset > input.len() || !input.is_char_boundary(offset) {
+            return self;
+        }
+        let start = input[..offset].rfind('\n').map_or(0, |newline| newline + 1);
+        let end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |newline| offset + newline);
+        let span_end = (offset + len).min(end);
+        self.location = Some(Location {
+            line: input[..offset].matches('\n').count() + 1,
+            column: input[start..offset].chars().count() + 1,
+            width: input
+                .get(offset..span_end)
+                .map_or(0, |span| span.chars().count())
+                .max(1),
+            text: input[start..end].trim_end_matches('\r').to_string(),
+        });
+        self
+    }
+
+    pub fn location(&self) -> Option<&Location> {
+        self.location.as_ref()
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+/// The message, and once located the line and column followed by the line with the text the
+/// error is about underlined.
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.location {
+            Some(location) => write!(
+                f,
+                "line {}, column {}: {}\n    {}\n    {}{}",
+                location.line,
+                location.column,
+                self.message,
+                location.text,
+                " ".repeat(location.column - 1),
+                "^".repeat(location.width)
+            ),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl From<ParseIntError> for ParseError {
+    fn from(err: ParseIntError) -> Self {
+        Self::new(format!("invalid number: {}", err))
+    }
+}
+
+#[derive(Debug)]
+pub enum Error {
+    /// The day number is outside of 1..=25 or isn't a number at all.
+    InvalidDay(String),
+    /// There are no solutions for the year at all.
+    InvalidYear(u16),
+    /// The day is in range but has no solution yet.
+    NotImplemented(u8),
+    /// The part isn't 1 or 2.
+    InvalidPart(u8),
+    /// The day rejected its input.
+    Parse { day: u8, source: ParseError },
+    /// The day panicked while solving a part.
+    Solver { day: u8, part: u8, message: String },
+}
+
+impl Error {
+    /// The status the CLI exits with, distinct per kind of failure.
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Error::InvalidDay(_)
+            | Error::InvalidYear(_)
+            | Error::NotImplemented(_)
+            | Error::InvalidPart(_) => 2,
+            Error::Parse { .. } => 6,
+            Error::Solver { .. } => 7,
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::InvalidDay(day) => write!(
+                f,
+                "Invalid day `{}`, please provide a day number between 1 and 25 or `all`",
+                day
+            ),
+            Error::InvalidYear(year) => write!(f, "There are no solutions for {}", year),
+            Error::NotImplemented(day) => write!(f, "Day {} not implemented yet", day),
+            Error::InvalidPart(part) => {
+                write!(f, "Invalid part {}, days only have parts 1 and 2", part)
+            }
+            Error::Parse { day, source } => {
+                write!(f, "Could not parse input of day {}: {}", day, source)
+            }
+            Error::Solver { day, part, message } => {
+                write!(f, "Day {} part {} panicked: {}", day, part, message)
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Parse { source, .. } => Some(source),
+            Error::InvalidDay(_)
+            | Error::InvalidYear(_)
+            | Error::NotImplemented(_)
+            | Error::InvalidPart(_)
+            | Error::Solver { .. } => None,
+        }
+    }
+}
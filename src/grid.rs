@@ -0,0 +1,456 @@
+//! Positions, directions and grids for the days played out on a map.
+//!
+//! `x` grows to the right and `y` grows down, like the rows of a puzzle's map are read.
+
+use std::collections::HashMap;
+use std::fmt::{self, Display, Formatter};
+use std::ops::{Add, AddAssign, Index, IndexMut, Sub, SubAssign};
+
+use crate::error::ParseError;
+
+/// A position on a grid, or the offset between two.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Pos {
+    pub x: i32,
+    pub y: i32,
+}
+
+/// The offsets to the 8 positions around a position, clockwise from the top left.
+const AROUND: [Pos; 8] = [
+    Pos::new(-1, -1),
+    Pos::new(0, -1),
+    Pos::new(1, -1),
+    Pos::new(1, 0),
+    Pos::new(1, 1),
+    Pos::new(0, 1),
+    Pos::new(-1, 1),
+    Pos::new(-1, 0),
+];
+
+impl Pos {
+    pub const fn new(x: i32, y: i32) -> Self {
+        Self { x, y }
+    }
+
+    pub fn manhattan(self, other: Pos) -> u32 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// The 4 positions next to this one, in the order of [`Dir::ALL`].
+    pub fn neighbours(self) -> impl Iterator<Item = Pos> {
+        Dir::ALL.into_iter().map(move |dir| self + dir)
+    }
+
+    /// The 8 positions around this one, diagonals included.
+    pub fn neighbours8(self) -> impl Iterator<Item = Pos> {
+        AROUND.into_iter().map(move |offset| self + offset)
+    }
+}
+
+impl Add for Pos {
+    type Output = Pos;
+
+    fn add(self, rhs: Pos) -> Pos {
+        Pos::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl Sub for Pos {
+    type Output = Pos;
+
+    fn sub(self, rhs: Pos) -> Pos {
+        Pos::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl AddAssign for Pos {
+    fn add_assign(&mut self, rhs: Pos) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for Pos {
+    fn sub_assign(&mut self, rhs: Pos) {
+        *self = *self - rhs;
+    }
+}
+
+impl Add<Dir> for Pos {
+    type Output = Pos;
+
+    fn add(self, rhs: Dir) -> Pos {
+        self + rhs.offset()
+    }
+}
+
+impl AddAssign<Dir> for Pos {
+    fn add_assign(&mut self, rhs: Dir) {
+        *self = *self + rhs;
+    }
+}
+
+/// One of the 4 directions on a grid, `Up` being towards the first row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Dir {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Dir {
+    /// Every direction, clockwise from `Up`.
+    pub const ALL: [Dir; 4] = [Dir::Up, Dir::Right, Dir::Down, Dir::Left];
+
+    /// The step one cell in this direction takes.
+    pub fn offset(self) -> Pos {
+        match self {
+            Dir::Up => Pos::new(0, -1),
+            Dir::Right => Pos::new(1, 0),
+            Dir::Down => Pos::new(0, 1),
+            Dir::Left => Pos::new(-1, 0),
+        }
+    }
+
+    pub fn turn_left(self) -> Dir {
+        match self {
+            Dir::Up => Dir::Left,
+            Dir::Right => Dir::Up,
+            Dir::Down => Dir::Right,
+            Dir::Left => Dir::Down,
+        }
+    }
+
+    pub fn turn_right(self) -> Dir {
+        match self {
+            Dir::Up => Dir::Right,
+            Dir::Right => Dir::Down,
+            Dir::Down => Dir::Left,
+            Dir::Left => Dir::Up,
+        }
+    }
+
+    pub fn opposite(self) -> Dir {
+        self.turn_left().turn_left()
+    }
+
+    /// The direction drawn as `^`, `>`, `v` or `<` in maps.
+    pub fn from_arrow(c: char) -> Option<Dir> {
+        match c {
+            '^' => Some(Dir::Up),
+            '>' => Some(Dir::Right),
+            'v' => Some(Dir::Down),
+            '<' => Some(Dir::Left),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Dir {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let arrow = match self {
+            Dir::Up => '^',
+            Dir::Right => '>',
+            Dir::Down => 'v',
+            Dir::Left => '<',
+        };
+        write!(f, "{}", arrow)
+    }
+}
+
+/// Calls `cell` with the position and character of every character of the map in `input`. Its
+/// errors point at that character unless they already point somewhere.
+fn for_each_char(
+    input: &str,
+    mut cell: impl FnMut(Pos, char) -> Result<(), ParseError>,
+) -> Result<(), ParseError> {
+    for (y, line) in input.lines().enumerate() {
+        for (x, (i, c)) in line.char_indices().enumerate() {
+            cell(Pos::new(x as i32, y as i32), c)
+                .map_err(|err| err.at(&line[i..i + c.len_utf8()]))?;
+        }
+    }
+    Ok(())
+}
+
+/// A rectangular grid with a value in every cell.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    /// Row after row.
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            cells: vec![value; width * height],
+            width,
+            height,
+        }
+    }
+
+    /// Parses a map with a character per cell, turned into a value by `cell`. Every line has to
+    /// be as long as the first one.
+    pub fn parse(
+        input: &str,
+        mut cell: impl FnMut(Pos, char) -> Result<T, ParseError>,
+    ) -> Result<Self, ParseError> {
+        let width = input.lines().next().map_or(0, |line| line.chars().count());
+        let height = input.lines().count();
+        if let Some((y, line)) = input
+            .lines()
+            .enumerate()
+            .find(|(_, line)| line.chars().count() != width)
+        {
+            return Err(ParseError::new(format!(
+                "line {} of the map is {} wide, the first one is {} wide",
+                y + 1,
+                line.chars().count(),
+                width
+            ))
+            .at(line));
+        }
+        let mut cells = Vec::with_capacity(width * height);
+        for_each_char(input, |pos, c| {
+            cells.push(cell(pos, c)?);
+            Ok(())
+        })?;
+        Ok(Self {
+            cells,
+            width,
+            height,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn index_of(&self, pos: Pos) -> Option<usize> {
+        let (x, y) = (usize::try_from(pos.x).ok()?, usize::try_from(pos.y).ok()?);
+        (x < self.width && y < self.height).then_some(x + y * self.width)
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        self.index_of(pos).is_some()
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.index_of(pos).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.index_of(pos).map(|index| &mut self.cells[index])
+    }
+
+    /// Every position of the grid, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width as i32;
+        (0..self.height as i32).flat_map(move |y| (0..width).map(move |x| Pos::new(x, y)))
+    }
+
+    /// Every cell of the grid with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// The neighbours of `pos` inside the grid, see [`Pos::neighbours`].
+    pub fn neighbours(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        pos.neighbours().filter(|&pos| self.contains(pos))
+    }
+
+    /// The neighbours of `pos` inside the grid, diagonals included.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        pos.neighbours8().filter(|&pos| self.contains(pos))
+    }
+
+    /// The cells from `from` towards `dir` up to the edge of the grid, without `from` itself.
+    pub fn ray(&self, from: Pos, dir: Dir) -> impl Iterator<Item = &T> + '_ {
+        let mut pos = from;
+        std::iter::from_fn(move || {
+            pos += dir;
+            self.get(pos)
+        })
+    }
+
+    /// A grid of the same size with `f` applied to every cell.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos).unwrap_or_else(|| {
+            panic!(
+                "{:?} is outside of the {}x{} grid",
+                pos, self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{:?} is outside of the {}x{} grid", pos, width, height))
+    }
+}
+
+/// Draws the grid row by row, like the maps of the puzzles.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// A grid with values in only some of its cells and no bounds, for maps that grow or are
+/// mostly empty.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Pos, T>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self {
+            cells: HashMap::new(),
+        }
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parses a map with a character per cell, where `cell` turns the character into a value or
+    /// `None` to leave the cell empty.
+    pub fn parse(
+        input: &str,
+        mut cell: impl FnMut(Pos, char) -> Result<Option<T>, ParseError>,
+    ) -> Result<Self, ParseError> {
+        let mut grid = Self::new();
+        for_each_char(input, |pos, c| {
+            if let Some(value) = cell(pos, c)? {
+                grid.insert(pos, value);
+            }
+            Ok(())
+        })?;
+        Ok(grid)
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        self.cells.contains_key(&pos)
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.cells.get(&pos)
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.cells.get_mut(&pos)
+    }
+
+    /// Puts `value` at `pos`, returning what was there.
+    pub fn insert(&mut self, pos: Pos, value: T) -> Option<T> {
+        self.cells.insert(pos, value)
+    }
+
+    pub fn remove(&mut self, pos: Pos) -> Option<T> {
+        self.cells.remove(&pos)
+    }
+
+    /// The positions with a value, in no particular order.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> + '_ {
+        self.cells.keys().copied()
+    }
+
+    /// The cells with a value and their positions, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.cells.iter().map(|(&pos, value)| (pos, value))
+    }
+
+    /// The top left and bottom right corners of the smallest rectangle around every value,
+    /// `None` if there are none.
+    pub fn bounds(&self) -> Option<(Pos, Pos)> {
+        let mut positions = self.positions();
+        let first = positions.next()?;
+        Some(positions.fold((first, first), |(min, max), pos| {
+            (
+                Pos::new(min.x.min(pos.x), min.y.min(pos.y)),
+                Pos::new(max.x.max(pos.x), max.y.max(pos.y)),
+            )
+        }))
+    }
+}
+
+impl<T> Index<Pos> for SparseGrid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{:?} has no value", pos))
+    }
+}
+
+impl<T> FromIterator<(Pos, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Pos, T)>>(iter: I) -> Self {
+        Self {
+            cells: iter.into_iter().collect(),
+        }
+    }
+}
+
+/// Draws the [`SparseGrid::bounds`] row by row, with `.` for the empty cells.
+impl<T: Display> Display for SparseGrid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let Some((min, max)) = self.bounds() else {
+            return Ok(());
+        };
+        for y in min.y..=max.y {
+            for x in min.x..=max.x {
+                match self.get(Pos::new(x, y)) {
+                    Some(value) => write!(f, "{}", value)?,
+                    None => write!(f, ".")?,
+                }
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
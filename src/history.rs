@@ -0,0 +1,144 @@
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::cli_error::Error;
+use crate::report::SubmitStatus;
+
+const HISTORY_FILE_PATH: &str = "logs/submissions.jsonl";
+
+/// One answer that was posted to the site.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Submission {
+    /// Histories written before there were other years only hold 2022's submissions.
+    #[serde(default = "first_year")]
+    pub year: u16,
+    pub day: u8,
+    pub level: u8,
+    pub answer: String,
+    /// Seconds since the Unix epoch.
+    pub timestamp: u64,
+    pub outcome: SubmitStatus,
+}
+
+fn first_year() -> u16 {
+    2022
+}
+
+impl Submission {
+    pub fn now(year: u16, day: u8, level: u8, answer: &str, outcome: SubmitStatus) -> Self {
+        Self {
+            year,
+            day,
+            level,
+            answer: answer.to_string(),
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |since| since.as_secs()),
+            outcome,
+        }
+    }
+}
+
+/// Every submission so far, kept in `logs/submissions.jsonl` with one JSON object per line.
+#[derive(Debug, Default)]
+pub struct History {
+    submissions: Vec<Submission>,
+}
+
+impl History {
+    /// Loads the history, it's empty if nothing was submitted yet.
+    pub fn load() -> Result<Self, Error> {
+        let contents = match fs::read_to_string(HISTORY_FILE_PATH) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(err) => {
+                return Err(Error::io(
+                    format!("Could not read submission history ({})", HISTORY_FILE_PATH),
+                    err,
+                ))
+            }
+        };
+        let submissions = contents
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(serde_json::from_str)
+            .collect::<Result<_, _>>()
+            .map_err(|err| {
+                Error::io(
+                    format!("Could not parse submission history ({})", HISTORY_FILE_PATH),
+                    io::Error::new(io::ErrorKind::InvalidData, err),
+                )
+            })?;
+        Ok(Self { submissions })
+    }
+
+    /// Appends `submission` to the history file.
+    pub fn record(submission: &Submission) -> Result<(), Error> {
+        let line = serde_json::to_string(submission)
+            .expect("submissions only hold strings and numbers")
+            + "\n";
+        fs::create_dir_all("logs")
+            .and_then(|_| {
+                OpenOptions::new()
+                    .create(true)
+                    .append(true)
+                    .open(HISTORY_FILE_PATH)
+            })
+            .and_then(|mut file| file.write_all(line.as_bytes()))
+            .map_err(|err| {
+                Error::io(
+                    format!("Could not write submission history ({})", HISTORY_FILE_PATH),
+                    err,
+                )
+            })
+    }
+
+    /// Why `answer` can't be right for `level` of `day` of `year` given earlier submissions, if it
+    /// can't.
+    pub fn ruling_out(&self, year: u16, day: u8, level: u8, answer: &str) -> Option<String> {
+        let submissions = self.submissions.iter().filter(|submission| {
+            submission.year == year && submission.day == day && submission.level == level
+        });
+
+        let mut lowest_too_high: Option<(i128, &str)> = None;
+        let mut highest_too_low: Option<(i128, &str)> = None;
+        for submission in submissions {
+            match submission.outcome {
+                SubmitStatus::TooHigh | SubmitStatus::TooLow | SubmitStatus::Incorrect
+                    if submission.answer == answer =>
+                {
+                    return Some(format!("{} was already rejected", answer));
+                }
+                _ => {}
+            }
+
+            let Ok(value) = submission.answer.parse::<i128>() else {
+                continue;
+            };
+            match submission.outcome {
+                SubmitStatus::TooHigh if lowest_too_high.is_none_or(|(high, _)| value < high) => {
+                    lowest_too_high = Some((value, &submission.answer));
+                }
+                SubmitStatus::TooLow if highest_too_low.is_none_or(|(low, _)| value > low) => {
+                    highest_too_low = Some((value, &submission.answer));
+                }
+                _ => {}
+            }
+        }
+
+        let value = answer.parse::<i128>().ok()?;
+        match (lowest_too_high, highest_too_low) {
+            (Some((high, high_answer)), _) if value >= high => Some(format!(
+                "{} is too high, {} already was",
+                answer, high_answer
+            )),
+            (_, Some((low, low_answer))) if value <= low => {
+                Some(format!("{} is too low, {} already was", answer, low_answer))
+            }
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,18 @@
+//! Advent of Code solutions, grouped by year.
+//!
+//! Every day lives in a module of its year in [`days`], like `days::y2022::day1`, and implements
+//! [`Day`]. Use [`solve`] to get the answer to a
+//! part without any network access or printing.
+//!
+//! Puzzles that draw their answer in capital letters can read them with [`ocr`], the ones played
+//! out on a map share the types of [`grid`]. The helpers of [`parse`] read inputs into what the
+//! days need, with errors that point at the offending line and column.
+
+pub mod days;
+pub mod error;
+pub mod grid;
+pub mod ocr;
+pub mod parse;
+
+pub use days::{solve, Answer, Day};
+pub use error::{Error, Location, ParseError};
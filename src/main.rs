@@ -1,13 +1,314 @@
-use clap::{arg, command};
+use std::io::{self, Read};
+use std::net::TcpListener;
+use std::path::PathBuf;
+use std::process;
+use std::time::{Duration, Instant};
+
+use clap::error::ErrorKind;
+use clap::{arg, command, value_parser, Command};
 use colored::*;
-use reqwest::blocking::Client;
 
-use crate::days::{nanos_to_string, run_day};
+use advent_of_code::days::latest_year;
+
+use crate::bench::{bench_day, Baseline};
+use crate::cli_error::Error;
+use crate::mock::MockServer;
+use crate::puzzle::read_puzzle;
+use crate::report::{print_header, print_report, DayReport, Format};
+use crate::runner::{
+    nanos_to_string, refetch_input, run_day, run_days_parallel, InputSource, Output, RunOptions,
+};
+use crate::scaffold::new_day;
+use crate::session::find_session;
+use crate::site::{Site, DEFAULT_BASE_URL};
+use crate::verify::{verify_day, Verdict};
+use crate::watch::watch_day;
+
+mod alloc;
+mod answers;
+mod bench;
+mod cli_error;
+mod history;
+mod mock;
+mod puzzle;
+mod report;
+mod runner;
+mod scaffold;
+mod session;
+mod site;
+mod verify;
+mod watch;
+
+#[global_allocator]
+static ALLOCATOR: alloc::CountingAllocator = alloc::CountingAllocator;
+
+fn report(err: &Error) {
+    eprintln!("{}", err.to_string().bold().red());
+}
+
+/// The days selected by a `DAY` argument, `all` being every day.
+fn parse_days(day: &str) -> Result<Vec<u8>, Error> {
+    if day == "all" {
+        Ok((1..=25).collect())
+    } else {
+        day.parse::<u8>()
+            .map(|day| vec![day])
+            .map_err(|_| Error::Day(advent_of_code::Error::InvalidDay(day.to_string())))
+    }
+}
+
+fn verify(year: u16, day: &str) -> i32 {
+    let days = match parse_days(day) {
+        Ok(days) => days,
+        Err(err) => {
+            report(&err);
+            return err.exit_code();
+        }
+    };
+
+    let mut total = Verdict::default();
+    let mut exit_code = 0;
+    for day in days {
+        match verify_day(year, day) {
+            Ok(verdict) => total += verdict,
+            Err(err) => {
+                report(&err);
+                if exit_code == 0 {
+                    exit_code = err.exit_code();
+                }
+            }
+        }
+    }
+
+    println!(
+        "{} {} passed, {} failed, {} unchecked",
+        "Verified:".bold(),
+        total.passed.to_string().green(),
+        total.failed.to_string().red(),
+        total.unchecked
+    );
+    if exit_code == 0 && total.failed > 0 {
+        exit_code = 1;
+    }
+    exit_code
+}
+
+fn bench(year: u16, day: &str, warmup: usize, iterations: usize, save_baseline: bool) -> i32 {
+    let result = parse_days(day).and_then(|days| {
+        let mut baseline = Baseline::load()?;
+        let mut exit_code = 0;
+        for day in days {
+            match bench_day(year, day, warmup, iterations, baseline.get(year, day)) {
+                Ok(stats) => {
+                    if save_baseline || baseline.get(year, day).is_none() {
+                        baseline.set(year, day, stats.baseline());
+                    }
+                }
+                Err(err) => {
+                    report(&err);
+                    if exit_code == 0 {
+                        exit_code = err.exit_code();
+                    }
+                }
+            }
+        }
+        baseline.save()?;
+        Ok(exit_code)
+    });
+    result.unwrap_or_else(|err| {
+        report(&err);
+        err.exit_code()
+    })
+}
+
+fn refetch(year: u16, day: &str, site: &Site) -> i32 {
+    let days = match parse_days(day) {
+        Ok(days) => days,
+        Err(err) => {
+            report(&err);
+            return err.exit_code();
+        }
+    };
+
+    let mut exit_code = 0;
+    for day in days {
+        println!("{}", format!("Day {}", day).bold().green());
+        if let Err(err) = refetch_input(year, day, site) {
+            report(&err);
+            if exit_code == 0 {
+                exit_code = err.exit_code();
+            }
+        }
+    }
+    exit_code
+}
 
-mod days;
+fn new(year: u16, day: &str, site: &Site) -> i32 {
+    let result = day
+        .parse::<u8>()
+        .map_err(|_| Error::Day(advent_of_code::Error::InvalidDay(day.to_string())))
+        .and_then(|day| new_day(year, day, site));
+    match result {
+        Ok(()) => 0,
+        Err(err) => {
+            report(&err);
+            err.exit_code()
+        }
+    }
+}
+
+fn read(year: u16, day: &str, site: &Site) -> i32 {
+    let result = day
+        .parse::<u8>()
+        .map_err(|_| Error::Day(advent_of_code::Error::InvalidDay(day.to_string())))
+        .and_then(|day| read_puzzle(year, day, site));
+    match result {
+        Ok(()) => 0,
+        Err(err) => {
+            report(&err);
+            err.exit_code()
+        }
+    }
+}
+
+fn watch(year: u16, day: &str) -> i32 {
+    let result = day
+        .parse::<u8>()
+        .map_err(|_| Error::Day(advent_of_code::Error::InvalidDay(day.to_string())))
+        .and_then(|day| watch_day(year, day));
+    match result {
+        Ok(()) => 0,
+        Err(err) => {
+            report(&err);
+            err.exit_code()
+        }
+    }
+}
+
+fn check_session(site: &Site) -> i32 {
+    let result = find_session()
+        .and_then(|(session, source)| site.check_session(&session).map(|user| (user, source)));
+    match result {
+        Ok((user, source)) => {
+            println!(
+                "{} {} {}",
+                "Logged in as".bold().green(),
+                if user.is_empty() {
+                    "an unknown user"
+                } else {
+                    &user
+                },
+                format!("(session from {})", source).dimmed()
+            );
+            0
+        }
+        Err(err) => {
+            report(&err);
+            err.exit_code()
+        }
+    }
+}
+
+fn mock_server(port: u16, server: MockServer) -> i32 {
+    let listener = match TcpListener::bind(("127.0.0.1", port)) {
+        Ok(listener) => listener,
+        Err(err) => {
+            report(&Error::io("Could not start mock server", err));
+            return 3;
+        }
+    };
+    match listener.local_addr() {
+        Ok(addr) => println!("Listening on http://{}", addr),
+        Err(err) => {
+            report(&Error::io("Could not start mock server", err));
+            return 3;
+        }
+    }
+    match server.serve(listener) {
+        Ok(()) => 0,
+        Err(err) => {
+            let err = Error::io("Mock server stopped", err);
+            report(&err);
+            err.exit_code()
+        }
+    }
+}
 
 fn main() {
     let matches = command!()
+        .subcommand_negates_reqs(true)
+        .args_conflicts_with_subcommands(true)
+        .subcommand(
+            Command::new("verify")
+                .about("Re-run days offline and compare with the stored answers")
+                .arg(arg!([DAY] "The day to verify").default_value("all")),
+        )
+        .subcommand(
+            Command::new("bench")
+                .about("Benchmark days over many iterations and compare with the baseline")
+                .arg(arg!([DAY] "The day to benchmark").default_value("all"))
+                .arg(
+                    arg!(-w --warmup <N> "Iterations to run before measuring")
+                        .value_parser(value_parser!(usize))
+                        .default_value("10"),
+                )
+                .arg(
+                    arg!(-n --iterations <N> "Iterations to measure")
+                        .value_parser(value_parser!(usize))
+                        .default_value("100"),
+                )
+                .arg(arg!(
+                    -b --savebaseline "Replace the baseline with this run"
+                )),
+        )
+        .subcommand(
+            Command::new("mock-server")
+                .about("Serve the fixtures like adventofcode.com, for testing")
+                .hide(true)
+                .arg(
+                    arg!(--port <PORT> "The port to listen on, any free one if 0")
+                        .value_parser(value_parser!(u16))
+                        .default_value("0"),
+                )
+                .arg(
+                    arg!(--fixtures <DIR> "Where the inputs and answers of the days are")
+                        .default_value("tests/fixtures"),
+                )
+                .arg(
+                    arg!(--cooldown <SECS> "How long to refuse answers after a wrong one")
+                        .value_parser(value_parser!(u64))
+                        .default_value("0"),
+                )
+                .arg(arg!(--session <SESSION> "The only session that is logged in").required(false)),
+        )
+        .subcommand(
+            Command::new("refetch")
+                .about("Download inputs again, replacing the cached ones")
+                .arg(arg!(<DAY> "The day to download the input of")),
+        )
+        .subcommand(
+            Command::new("new")
+                .about("Add a day from the template in src/days/day.rs and fetch its input")
+                .arg(arg!(<DAY> "The day to add")),
+        )
+        .subcommand(
+            Command::new("read")
+                .about("Print the puzzle description and save its examples as candidate fixtures")
+                .arg(arg!(<DAY> "The day to read")),
+        )
+        .subcommand(
+            Command::new("watch")
+                .about("Rerun a day on its example and input whenever they or its source change, without submitting")
+                .arg(arg!(<DAY> "The day to watch")),
+        )
+        .subcommand(
+            Command::new("session")
+                .about("Manage the session cookie")
+                .subcommand_required(true)
+                .subcommand(
+                    Command::new("check").about("Check that the session cookie is still logged in"),
+                ),
+        )
         .arg(arg!(
             <DAY> "The day to run"
         ))
@@ -32,82 +333,314 @@ fn main() {
         .arg(arg!(
             -i --dontinput "Don't auto-get the input"
         ))
+        .arg(arg!(
+            -w --wait "Wait out the cooldown after a wrong answer and submit anyway"
+        ))
+        .arg(
+            arg!(
+                -j --jobs <N> "Run `all` on N threads at once"
+            )
+            .required(false)
+            .value_parser(value_parser!(usize)),
+        )
+        .arg(
+            arg!(
+                --input <PATH> "Run on the input in PATH, or stdin if -, without submitting"
+            )
+            .required(false)
+            .value_parser(value_parser!(PathBuf)),
+        )
+        .arg(
+            arg!(
+                --example "Run on the example fixture, without submitting"
+            )
+            .conflicts_with("input"),
+        )
+        .arg(
+            arg!(
+                --part <PART> "Only run part 1 or part 2"
+            )
+            .required(false)
+            .value_parser(value_parser!(u8).range(1..=2)),
+        )
+        .arg(
+            arg!(
+                --"parse-only" "Only parse the input, timing it"
+            )
+            .conflicts_with("part"),
+        )
+        .arg(
+            arg!(
+                --mem "Report the peak heap and allocations of every phase"
+            )
+            .conflicts_with("jobs"),
+        )
+        .arg(
+            arg!(
+                --timeout <SECS> "Give up on a day after SECS seconds"
+            )
+            .required(false)
+            .value_parser(value_parser!(u64)),
+        )
+        .arg(
+            arg!(
+                -f --format <FORMAT> "How to print the results"
+            )
+            .value_parser(["text", "json", "csv"])
+            .default_value("text"),
+        )
+        .arg(
+            arg!(
+                --baseurl <URL> "The Advent of Code website to talk to"
+            )
+            .env("AOC_BASE_URL")
+            .default_value(DEFAULT_BASE_URL)
+            .global(true),
+        )
+        .arg(
+            arg!(
+                --year <YEAR> "The event to run the days of, the latest one if not given"
+            )
+            .required(false)
+            .value_parser(value_parser!(u16))
+            .global(true),
+        )
         .get_matches();
+    let year = matches
+        .get_one::<u16>("year")
+        .copied()
+        .unwrap_or_else(latest_year);
+
+    match matches.subcommand() {
+        Some(("verify", sub_matches)) => {
+            process::exit(verify(year, sub_matches.get_one::<String>("DAY").unwrap()));
+        }
+        Some(("bench", sub_matches)) => {
+            process::exit(bench(
+                year,
+                sub_matches.get_one::<String>("DAY").unwrap(),
+                *sub_matches.get_one::<usize>("warmup").unwrap(),
+                *sub_matches.get_one::<usize>("iterations").unwrap(),
+                *sub_matches.get_one::<bool>("savebaseline").unwrap(),
+            ));
+        }
+        Some(("mock-server", sub_matches)) => {
+            process::exit(mock_server(
+                *sub_matches.get_one::<u16>("port").unwrap(),
+                MockServer::new(
+                    sub_matches.get_one::<String>("fixtures").unwrap(),
+                    Duration::from_secs(*sub_matches.get_one::<u64>("cooldown").unwrap()),
+                    sub_matches.get_one::<String>("session").cloned(),
+                ),
+            ));
+        }
+        Some(("refetch", sub_matches)) => {
+            let site = Site::new(matches.get_one::<String>("baseurl").unwrap());
+            process::exit(refetch(
+                year,
+                sub_matches.get_one::<String>("DAY").unwrap(),
+                &site,
+            ));
+        }
+        Some(("new", sub_matches)) => {
+            let site = Site::new(matches.get_one::<String>("baseurl").unwrap());
+            process::exit(new(
+                year,
+                sub_matches.get_one::<String>("DAY").unwrap(),
+                &site,
+            ));
+        }
+        Some(("read", sub_matches)) => {
+            let site = Site::new(matches.get_one::<String>("baseurl").unwrap());
+            process::exit(read(
+                year,
+                sub_matches.get_one::<String>("DAY").unwrap(),
+                &site,
+            ));
+        }
+        Some(("watch", sub_matches)) => {
+            process::exit(watch(year, sub_matches.get_one::<String>("DAY").unwrap()));
+        }
+        Some(("session", sub_matches)) => {
+            let site = Site::new(matches.get_one::<String>("baseurl").unwrap());
+            match sub_matches.subcommand() {
+                Some(("check", _)) => process::exit(check_session(&site)),
+                _ => unreachable!("a session subcommand is required"),
+            }
+        }
+        _ => {}
+    }
 
     let day = matches.get_one::<String>("DAY").unwrap();
-    let time = *matches.get_one::<bool>("time").unwrap();
-    let dont_print = *matches.get_one::<bool>("dontprint").unwrap();
-    let dontsubmit = *matches.get_one::<bool>("dontsubmit").unwrap();
-    let dontinput = *matches.get_one::<bool>("dontinput").unwrap();
-    let client = Client::new();
+    let input_path = matches.get_one::<PathBuf>("input");
+    if day == "all" && input_path.is_some() {
+        clap::Error::raw(
+            ErrorKind::ArgumentConflict,
+            "--input can't be used with all, every day needs its own input\n",
+        )
+        .exit();
+    }
+    let input = match input_path {
+        Some(path) if path.as_os_str() == "-" => {
+            let mut input = String::new();
+            if let Err(err) = io::stdin().read_to_string(&mut input) {
+                let err = Error::io("Could not read input from stdin", err);
+                report(&err);
+                process::exit(err.exit_code());
+            }
+            InputSource::Contents(input.into())
+        }
+        Some(path) => InputSource::File(path.clone()),
+        None if *matches.get_one::<bool>("example").unwrap() => InputSource::Example,
+        None => InputSource::Cached,
+    };
+    let options = RunOptions {
+        time: *matches.get_one::<bool>("time").unwrap(),
+        dont_print: *matches.get_one::<bool>("dontprint").unwrap(),
+        dont_submit: *matches.get_one::<bool>("dontsubmit").unwrap(),
+        dont_input: *matches.get_one::<bool>("dontinput").unwrap(),
+        wait: *matches.get_one::<bool>("wait").unwrap(),
+        input,
+        part: matches.get_one::<u8>("part").copied(),
+        parse_only: *matches.get_one::<bool>("parse-only").unwrap(),
+        mem: *matches.get_one::<bool>("mem").unwrap(),
+        timeout: matches
+            .get_one::<u64>("timeout")
+            .map(|&secs| Duration::from_secs(secs)),
+    };
+    if options.mem {
+        alloc::enable();
+    }
+    let format = Format::from_name(matches.get_one::<String>("format").unwrap()).unwrap();
+    let total_time = *matches.get_one::<bool>("totaltime").unwrap() && format == Format::Text;
+    let new_output = || match format {
+        Format::Text => Output::Stdout,
+        Format::Json | Format::Csv => Output::Quiet,
+    };
+    let site = Site::new(matches.get_one::<String>("baseurl").unwrap());
+    print_header(format);
     if day == "all" {
-        let mut total_time = (0, 0, 0);
-        for day in 1..=25 {
-            let took_time = run_day(day, time, dont_print, dontsubmit, dontinput, &client);
-            if let Some((parsing, part1, part2)) = took_time {
-                total_time.0 += parsing;
-                total_time.1 += part1;
-                total_time.2 += part2;
+        let mut total = (0, 0, 0);
+        let mut exit_code = 0;
+        // Days that panicked or timed out, to list once the others are done.
+        let mut crashed = Vec::new();
+        let mut finish_day = |result: Result<DayReport, Error>| match result {
+            Ok(day_report) => {
+                print_report(format, &day_report);
+                total.0 += day_report.parse_nanos;
+                total.1 += day_report.part1_nanos;
+                total.2 += day_report.part2_nanos;
+            }
+            Err(err) => {
+                if let Some(day) = err.failed_day() {
+                    print_report(format, &DayReport::failed(year, day, &err));
+                }
+                report(&err);
+                if exit_code == 0 {
+                    exit_code = err.exit_code();
+                }
+                if let Error::Day(advent_of_code::Error::Solver { .. })
+                | Error::Panicked { .. }
+                | Error::TimedOut { .. } = err
+                {
+                    crashed.push(err);
+                }
+            }
+        };
+        let start = Instant::now();
+        match matches.get_one::<usize>("jobs") {
+            Some(&jobs) => {
+                let days = (1..=25).collect::<Vec<_>>();
+                run_days_parallel(year, &days, jobs, options, &site, |buffer, result| {
+                    if format == Format::Text {
+                        print!("{}", buffer);
+                    }
+                    finish_day(result);
+                });
+            }
+            None => {
+                for day in 1..=25 {
+                    finish_day(run_day(
+                        year,
+                        day,
+                        options.clone(),
+                        &site,
+                        &mut new_output(),
+                    ));
+                }
             }
         }
-        if *matches.get_one::<bool>("totaltime").unwrap() {
+        let wall_clock_time = start.elapsed().as_nanos();
+        if total_time {
             println!(
                 "{} {}",
                 "Total Parsing Time:".bold(),
-                nanos_to_string(total_time.0)
+                nanos_to_string(total.0, None)
             );
             println!(
                 "{} {}",
                 "Total Part 1 Time:".bold(),
-                nanos_to_string(total_time.1)
+                nanos_to_string(total.1, None)
             );
             println!(
                 "{} {}",
                 "Total Part 2 Time:".bold(),
-                nanos_to_string(total_time.2)
+                nanos_to_string(total.2, None)
             );
             println!(
                 "{} {}",
                 "Total Parts Time:".bold(),
-                nanos_to_string(total_time.1 + total_time.2)
+                nanos_to_string(total.1 + total.2, None)
             );
             println!(
                 "{} {}",
-                "Total Time:".bold(),
-                nanos_to_string(total_time.0 + total_time.1 + total_time.2)
+                "Total CPU Time:".bold(),
+                nanos_to_string(total.0 + total.1 + total.2, None)
             );
+            println!(
+                "{} {}",
+                "Wall-clock Time:".bold(),
+                nanos_to_string(wall_clock_time, None)
+            );
+        }
+        if !crashed.is_empty() {
+            eprintln!();
+            eprintln!("{}", "Days that didn't finish:".bold().red());
+            for err in &crashed {
+                eprintln!("  {}", err);
+            }
         }
-        return;
+        process::exit(exit_code);
     }
-    let total_time = run_day(
-        match day.parse::<u8>() {
-            Ok(day) => day,
-            Err(_) => {
+    let result = day
+        .parse::<u8>()
+        .map_err(|_| Error::Day(advent_of_code::Error::InvalidDay(day.to_string())))
+        .and_then(|day| run_day(year, day, options, &site, &mut new_output()));
+    match result {
+        Ok(day_report) => {
+            print_report(format, &day_report);
+            if total_time {
                 println!(
-                    "{}",
-                    "Please provide a valid day number or `all`".bold().red()
+                    "{} {}",
+                    "Total Parts Time:".bold(),
+                    nanos_to_string(day_report.part1_nanos + day_report.part2_nanos, None)
+                );
+                println!(
+                    "{} {}",
+                    "Total Time:".bold(),
+                    nanos_to_string(
+                        day_report.parse_nanos + day_report.part1_nanos + day_report.part2_nanos,
+                        None
+                    )
                 );
-                return;
             }
-        },
-        time,
-        dont_print,
-        dontsubmit,
-        dontinput,
-        &client,
-    );
-    if let Some((parsing, part1, part2)) = total_time {
-        if *matches.get_one::<bool>("totaltime").unwrap() {
-            println!(
-                "{} {}",
-                "Total Parts Time:".bold(),
-                nanos_to_string(part1 + part2)
-            );
-            println!(
-                "{} {}",
-                "Total Time:".bold(),
-                nanos_to_string(parsing + part1 + part2)
-            );
+        }
+        Err(err) => {
+            if let Some(day) = err.failed_day() {
+                print_report(format, &DayReport::failed(year, day, &err));
+            }
+            report(&err);
+            process::exit(err.exit_code());
         }
     }
 }
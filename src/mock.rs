@@ -0,0 +1,298 @@
+use std::collections::HashMap;
+use std::fs;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use crate::site::duration_to_string;
+
+/// A stand-in for adventofcode.com serving the days in a fixtures directory laid out like
+/// `tests/fixtures`, `YEAR/dayN/input.txt` being the input and `YEAR/dayN/partX.txt` the right
+/// answers.
+pub struct MockServer {
+    fixtures: PathBuf,
+    /// How many parts of each day of each year were answered correctly.
+    solved: HashMap<(u16, u8), u8>,
+    /// How long to refuse answers after a wrong one.
+    cooldown: Duration,
+    last_wrong_answer: Option<Instant>,
+    /// The only session cookie that is logged in, any is if there's none.
+    session: Option<String>,
+}
+
+struct Request {
+    method: String,
+    path: String,
+    headers: HashMap<String, String>,
+    body: String,
+}
+
+struct Response {
+    status: u16,
+    body: String,
+    location: Option<String>,
+}
+
+impl Response {
+    fn ok(body: impl Into<String>) -> Self {
+        Self::status(200, body)
+    }
+
+    fn status(status: u16, body: impl Into<String>) -> Self {
+        Self {
+            status,
+            body: body.into(),
+            location: None,
+        }
+    }
+
+    fn redirect(location: &str) -> Self {
+        Self {
+            status: 302,
+            body: String::new(),
+            location: Some(location.to_string()),
+        }
+    }
+}
+
+fn read_request(stream: &TcpStream) -> io::Result<Request> {
+    let mut reader = BufReader::new(stream);
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+    let mut request_line = request_line.split_whitespace();
+    let method = request_line.next().unwrap_or_default().to_string();
+    let path = request_line.next().unwrap_or_default().to_string();
+
+    let mut headers = HashMap::new();
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line)? == 0 || line.trim_end().is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            headers.insert(name.trim().to_lowercase(), value.trim().to_string());
+        }
+    }
+
+    let length = headers
+        .get("content-length")
+        .and_then(|length| length.parse().ok())
+        .unwrap_or(0);
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body)?;
+    Ok(Request {
+        method,
+        path,
+        headers,
+        body: String::from_utf8_lossy(&body).into_owned(),
+    })
+}
+
+fn write_response(mut stream: &TcpStream, response: Response) -> io::Result<()> {
+    let reason = match response.status {
+        200 => "OK",
+        302 => "Found",
+        400 => "Bad Request",
+        _ => "Not Found",
+    };
+    let location = response
+        .location
+        .map(|location| format!("Location: {}\r\n", location))
+        .unwrap_or_default();
+    write!(
+        stream,
+        "HTTP/1.1 {} {}\r\n{}Content-Type: text/html\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        response.status,
+        reason,
+        location,
+        response.body.len(),
+        response.body
+    )?;
+    stream.flush()
+}
+
+fn html_escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+fn url_decode(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'+' => decoded.push(b' '),
+            b'%' if i + 2 < bytes.len() => {
+                let hex = std::str::from_utf8(&bytes[i + 1..i + 3]).unwrap_or_default();
+                match u8::from_str_radix(hex, 16) {
+                    Ok(byte) => {
+                        decoded.push(byte);
+                        i += 2;
+                    }
+                    Err(_) => decoded.push(b'%'),
+                }
+            }
+            byte => decoded.push(byte),
+        }
+        i += 1;
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+fn parse_form(body: &str) -> HashMap<String, String> {
+    body.split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .map(|(name, value)| (url_decode(name), url_decode(value)))
+        .collect()
+}
+
+impl MockServer {
+    pub fn new(fixtures: impl Into<PathBuf>, cooldown: Duration, session: Option<String>) -> Self {
+        Self {
+            fixtures: fixtures.into(),
+            solved: HashMap::new(),
+            cooldown,
+            last_wrong_answer: None,
+            session,
+        }
+    }
+
+    fn fixture(&self, year: u16, day: u8, name: &str) -> Option<String> {
+        fs::read_to_string(
+            self.fixtures
+                .join(year.to_string())
+                .join(format!("day{}", day))
+                .join(name),
+        )
+        .ok()
+        .map(|contents| contents.replace("\r\n", "\n").trim_end().to_string())
+    }
+
+    fn puzzle_page(&self, year: u16, day: u8, logged_in: bool) -> String {
+        let solved = if logged_in {
+            self.solved.get(&(year, day)).copied().unwrap_or(0)
+        } else {
+            0
+        };
+        let mut page = format!(
+            "<main>\n<article class=\"day-desc\"><h2>--- Day {}: Mock ---</h2>\n<p>Solve <em>it</em>, for <a href=\"/{}/day/{}/input\">this input</a>:</p>\n<pre><code>{}\n</code></pre>\n</article>\n",
+            day,
+            year,
+            day,
+            html_escape(&self.fixture(year, day, "input.txt").unwrap_or_default())
+        );
+        if solved >= 1 {
+            page += &format!(
+                "<p>Your puzzle answer was <code>{}</code>.</p>\n",
+                self.fixture(year, day, "part1.txt").unwrap_or_default()
+            );
+            page += "<article class=\"day-desc\"><h2 id=\"part2\">--- Part Two ---</h2><p>Solve it again.</p></article>\n";
+        }
+        if solved >= 2 {
+            page += &format!(
+                "<p>Your puzzle answer was <code>{}</code>.</p>\n",
+                self.fixture(year, day, "part2.txt").unwrap_or_default()
+            );
+            page += "<p class=\"day-success\">Both parts of this puzzle are complete! They provide two gold stars: **</p>\n";
+        }
+        if !logged_in {
+            page += "<p>To play, please identify yourself via one of these services:</p>\n";
+        }
+        page + "</main>\n"
+    }
+
+    fn answer(&mut self, year: u16, day: u8, form: &HashMap<String, String>) -> String {
+        if let Some(left) = self
+            .last_wrong_answer
+            .and_then(|last| self.cooldown.checked_sub(last.elapsed()))
+        {
+            return format!("<main><article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have {} left to wait.</p></article></main>", duration_to_string(Duration::from_secs(left.as_secs() + 1)));
+        }
+
+        let solved = self.solved.get(&(year, day)).copied().unwrap_or(0);
+        let level = form.get("level").and_then(|level| level.parse::<u8>().ok());
+        if level != Some(solved + 1) {
+            return "<main><article><p>You don't seem to be solving the right level.  Did you already complete it?</p></article></main>".to_string();
+        }
+
+        let expected = self
+            .fixture(year, day, &format!("part{}.txt", solved + 1))
+            .unwrap_or_default();
+        let answer = form.get("answer").map_or("", |answer| answer.trim());
+        if answer == expected {
+            self.solved.insert((year, day), solved + 1);
+            return "<main><article><p>That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer to collecting enough star fruit.</p></article></main>".to_string();
+        }
+
+        self.last_wrong_answer = Some(Instant::now());
+        let hint = match (answer.parse::<i64>(), expected.parse::<i64>()) {
+            (Ok(answer), Ok(expected)) if answer > expected => "; your answer is too high",
+            (Ok(answer), Ok(expected)) if answer < expected => "; your answer is too low",
+            _ => "",
+        };
+        format!("<main><article><p>That's not the right answer{}.  If you're stuck, make sure you're using the full input data.</p></article></main>", hint)
+    }
+
+    fn handle(&mut self, request: Request) -> Response {
+        let logged_in = request
+            .headers
+            .get("cookie")
+            .and_then(|cookie| cookie.strip_prefix("session="))
+            .is_some_and(|session| {
+                !session.is_empty() && self.session.as_ref().is_none_or(|valid| valid == session)
+            });
+
+        let segments = request
+            .path
+            .trim_matches('/')
+            .split('/')
+            .collect::<Vec<_>>();
+        let year_day = match segments.as_slice() {
+            ["settings"] if logged_in => {
+                return Response::ok("<main><div class=\"user\">Mock user</div></main>\n")
+            }
+            ["settings"] => return Response::redirect("/auth/login"),
+            ["auth", "login"] => {
+                return Response::ok("<main><p>Log in to Advent of Code</p></main>\n")
+            }
+            [year, "day", day, ..] => year.parse::<u16>().ok().zip(day.parse::<u8>().ok()),
+            _ => None,
+        };
+        let (year, day) = match year_day {
+            Some((year, day)) if self.fixture(year, day, "input.txt").is_some() => (year, day),
+            _ => return Response::status(404, "404 Not Found\n"),
+        };
+
+        match (request.method.as_str(), &segments[3..]) {
+            ("GET", []) => Response::ok(self.puzzle_page(year, day, logged_in)),
+            (_, ["input" | "answer"]) if !logged_in => Response::status(
+                400,
+                "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n",
+            ),
+            ("GET", ["input"]) => {
+                Response::ok(self.fixture(year, day, "input.txt").unwrap_or_default() + "\n")
+            }
+            ("POST", ["answer"]) => {
+                Response::ok(self.answer(year, day, &parse_form(&request.body)))
+            }
+            _ => Response::status(404, "404 Not Found\n"),
+        }
+    }
+
+    /// Serves requests one after another on `listener` until the process is killed.
+    pub fn serve(mut self, listener: TcpListener) -> io::Result<()> {
+        for stream in listener.incoming() {
+            let stream = stream?;
+            let response = match read_request(&stream) {
+                Ok(request) => self.handle(request),
+                Err(_) => Response::status(400, "Malformed request\n"),
+            };
+            // A client hanging up early is its own problem, keep serving the others.
+            let _ = write_response(&stream, response);
+        }
+        Ok(())
+    }
+}
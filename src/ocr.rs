@@ -0,0 +1,205 @@
+//! Reads the capital letters some puzzles draw on a screen instead of answering with a number.
+//!
+//! Knows the two fonts the puzzles use: the 4x6 one most years draw with, and the 6x10 one of
+//! the larger screens. Only the letters that have shown up in puzzles are known.
+
+/// The glyphs of a font, each as rows of `#` and `.` without the blank columns around it.
+struct Font {
+    height: usize,
+    glyphs: &'static [(char, &'static [&'static str])],
+}
+
+const SMALL: Font = Font {
+    height: 6,
+    glyphs: &[
+        ('A', &[".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+        ('B', &["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+        ('C', &[".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+        ('E', &["####", "#...", "###.", "#...", "#...", "####"]),
+        ('F', &["####", "#...", "###.", "#...", "#...", "#..."]),
+        ('G', &[".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+        ('H', &["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+        ('I', &["###", ".#.", ".#.", ".#.", ".#.", "###"]),
+        ('J', &["..##", "...#", "...#", "...#", "#..#", ".##."]),
+        ('K', &["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+        ('L', &["#...", "#...", "#...", "#...", "#...", "####"]),
+        ('O', &[".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+        ('P', &["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+        ('R', &["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+        ('S', &[".###", "#...", "#...", ".##.", "...#", "###."]),
+        ('U', &["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+        ('Y', &["#...#", "#...#", ".#.#.", "..#..", "..#..", "..#.."]),
+        ('Z', &["####", "...#", "..#.", ".#..", "#...", "####"]),
+    ],
+};
+
+const LARGE: Font = Font {
+    height: 10,
+    glyphs: &[
+        (
+            'A',
+            &[
+                "..##..", ".#..#.", "#....#", "#....#", "#....#", "######", "#....#", "#....#",
+                "#....#", "#....#",
+            ],
+        ),
+        (
+            'B',
+            &[
+                "#####.", "#....#", "#....#", "#....#", "#####.", "#....#", "#....#", "#....#",
+                "#....#", "#####.",
+            ],
+        ),
+        (
+            'C',
+            &[
+                ".####.", "#....#", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....",
+                "#....#", ".####.",
+            ],
+        ),
+        (
+            'E',
+            &[
+                "######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....",
+                "#.....", "######",
+            ],
+        ),
+        (
+            'F',
+            &[
+                "######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....",
+                "#.....", "#.....",
+            ],
+        ),
+        (
+            'G',
+            &[
+                ".####.", "#....#", "#.....", "#.....", "#.....", "#..###", "#....#", "#....#",
+                "#...##", ".###.#",
+            ],
+        ),
+        (
+            'H',
+            &[
+                "#....#", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#",
+                "#....#", "#....#",
+            ],
+        ),
+        (
+            'J',
+            &[
+                "...###", "....#.", "....#.", "....#.", "....#.", "....#.", "....#.", "#...#.",
+                "#...#.", ".###..",
+            ],
+        ),
+        (
+            'K',
+            &[
+                "#....#", "#...#.", "#..#..", "#.#...", "##....", "##....", "#.#...", "#..#..",
+                "#...#.", "#....#",
+            ],
+        ),
+        (
+            'L',
+            &[
+                "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....",
+                "#.....", "######",
+            ],
+        ),
+        (
+            'N',
+            &[
+                "#....#", "##...#", "##...#", "#.#..#", "#.#..#", "#..#.#", "#..#.#", "#...##",
+                "#...##", "#....#",
+            ],
+        ),
+        (
+            'P',
+            &[
+                "#####.", "#....#", "#....#", "#....#", "#####.", "#.....", "#.....", "#.....",
+                "#.....", "#.....",
+            ],
+        ),
+        (
+            'R',
+            &[
+                "#####.", "#....#", "#....#", "#....#", "#####.", "#..#..", "#...#.", "#...#.",
+                "#....#", "#....#",
+            ],
+        ),
+        (
+            'X',
+            &[
+                "#....#", "#....#", ".#..#.", ".#..#.", "..##..", "..##..", ".#..#.", ".#..#.",
+                "#....#", "#....#",
+            ],
+        ),
+        (
+            'Z',
+            &[
+                "######", ".....#", ".....#", "....#.", "...#..", "..#...", ".#....", "#.....",
+                "#.....", "######",
+            ],
+        ),
+    ],
+};
+
+/// Reads the letters drawn in `rows`, where `lit` is a lit pixel and anything else is dark.
+///
+/// The font is told apart by the height of the drawing, blank rows around it don't count.
+/// Letters are told apart by the blank columns between them. `None` if the drawing isn't in
+/// either font or has a letter the font doesn't know.
+///
+/// ```
+/// let rows = [
+///     "#..#.###.",
+///     "#..#..#..",
+///     "####..#..",
+///     "#..#..#..",
+///     "#..#..#..",
+///     "#..#.###.",
+/// ];
+/// assert_eq!(advent_of_code::ocr::read_letters(&rows, '#').as_deref(), Some("HI"));
+/// ```
+pub fn read_letters<S: AsRef<str>>(rows: &[S], lit: char) -> Option<String> {
+    let pixels = rows
+        .iter()
+        .map(|row| row.as_ref().chars().map(|c| c == lit).collect::<Vec<_>>())
+        .skip_while(|row| !row.contains(&true))
+        .collect::<Vec<_>>();
+    let height = pixels.iter().rposition(|row| row.contains(&true))? + 1;
+    let pixels = &pixels[..height];
+    let font = [SMALL, LARGE]
+        .into_iter()
+        .find(|font| font.height == height)?;
+
+    let width = pixels.iter().map(Vec::len).max().unwrap_or(0);
+    let is_lit = |x: usize, y: usize| pixels[y].get(x).copied().unwrap_or(false);
+    let column_is_blank = |x: usize| (0..height).all(|y| !is_lit(x, y));
+
+    let mut letters = String::new();
+    let mut x = 0;
+    while x < width {
+        if column_is_blank(x) {
+            x += 1;
+            continue;
+        }
+        let start = x;
+        while x < width && !column_is_blank(x) {
+            x += 1;
+        }
+        let glyph = (0..height)
+            .map(|y| {
+                (start..x)
+                    .map(|x| if is_lit(x, y) { '#' } else { '.' })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>();
+        let (letter, _) = font
+            .glyphs
+            .iter()
+            .find(|(_, known)| known.iter().eq(&glyph))?;
+        letters.push(*letter);
+    }
+    Some(letters)
+}
@@ -0,0 +1,173 @@
+//! The pieces of parsing most days share, failing with a [`ParseError`] instead of panicking.
+//!
+//! Errors point at the slice of the text they are about, so when it is part of a day's input they
+//! are reported with its line and column, see [`ParseError::locate`].
+
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::error::ParseError;
+
+/// The first character of `text`, or its empty end if there is none, to point errors at.
+fn first_char(text: &str) -> &str {
+    let len = text.chars().next().map_or(0, char::len_utf8);
+    &text[..len]
+}
+
+/// Parses `text` as a `T`, ignoring whitespace around it.
+pub fn parse<T>(text: &str) -> Result<T, ParseError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let text = text.trim();
+    text.parse()
+        .map_err(|err| ParseError::new(format!("invalid value `{}`: {}", text, err)).at(text))
+}
+
+/// Every integer in `text`, in order. A `-` right before a number is its sign, unless it comes
+/// after a letter or digit like in `2-4`.
+///
+/// ```
+/// let numbers = advent_of_code::parse::integers::<i32>("x=-2, y=15 and 2-4").unwrap();
+/// assert_eq!(numbers, [-2, 15, 2, 4]);
+/// ```
+pub fn integers<T>(text: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let bytes = text.as_bytes();
+    let mut integers = vec![];
+    let mut i = 0;
+    while i < bytes.len() {
+        if !bytes[i].is_ascii_digit() {
+            i += 1;
+            continue;
+        }
+        let mut start = i;
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+        if start > 0
+            && bytes[start - 1] == b'-'
+            && (start < 2 || !bytes[start - 2].is_ascii_alphanumeric())
+        {
+            start -= 1;
+        }
+        integers.push(parse(&text[start..i])?);
+    }
+    Ok(integers)
+}
+
+/// Exactly `N` integers of `text`, separated by commas like `498,4`. Anything else between
+/// them, like a stray space or letter, is an error.
+///
+/// ```
+/// use advent_of_code::parse::integers_n;
+///
+/// assert_eq!(integers_n::<2, i32>("498,-4").unwrap(), [498, -4]);
+/// assert!(integers_n::<2, i32>("Q498,4").is_err());
+/// assert!(integers_n::<2, i32>("4 9").is_err());
+/// ```
+pub fn integers_n<const N: usize, T>(text: &str) -> Result<[T; N], ParseError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let fields = text.split(',').collect::<Vec<_>>();
+    if fields.len() != N {
+        return Err(ParseError::new(format!(
+            "expected {} numbers separated by `,`, found {}",
+            N,
+            fields.len()
+        ))
+        .at(text));
+    }
+    let numbers = fields
+        .into_iter()
+        .map(parse)
+        .collect::<Result<Vec<T>, _>>()?;
+    Ok(numbers
+        .try_into()
+        .unwrap_or_else(|_: Vec<T>| unreachable!("there are `N` fields")))
+}
+
+/// The blocks of lines of `input` that are separated by an empty line.
+pub fn blocks(input: &str) -> impl Iterator<Item = &str> {
+    input.trim_end_matches('\n').split("\n\n")
+}
+
+/// `text` split around the first `separator`.
+pub fn split_once<'a>(text: &'a str, separator: &str) -> Result<(&'a str, &'a str), ParseError> {
+    text.split_once(separator).ok_or_else(|| {
+        ParseError::new(format!("expected `{}`", separator.escape_debug())).at(first_char(text))
+    })
+}
+
+/// The fields of a text matched by [`template`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fields<'a> {
+    fields: Vec<&'a str>,
+}
+
+impl<'a> Fields<'a> {
+    pub fn len(&self) -> usize {
+        self.fields.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+
+    /// The text of field `index`, counted from 0. Panics if the template has fewer fields.
+    pub fn str(&self, index: usize) -> &'a str {
+        self.fields[index]
+    }
+
+    /// Field `index` parsed as a `T`, see [`parse`].
+    pub fn parse<T>(&self, index: usize) -> Result<T, ParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        parse(self.str(index))
+    }
+}
+
+/// Matches `text` against `pattern`, where `{}` stands for a field and the rest of the pattern
+/// has to be in `text` as is. A field ends where the text after it in the pattern first shows up,
+/// the last one at the end of `text` if nothing comes after it.
+///
+/// ```
+/// use advent_of_code::parse::template;
+///
+/// let fields = template("move {} from {} to {}", "move 3 from 1 to 2").unwrap();
+/// assert_eq!(fields.parse::<usize>(0).unwrap(), 3);
+/// assert_eq!(fields.str(2), "2");
+/// ```
+pub fn template<'a>(pattern: &str, text: &'a str) -> Result<Fields<'a>, ParseError> {
+    let expected = |literal: &str| format!("expected `{}`", literal.trim());
+    let mut literals = pattern.split("{}");
+    let prefix = literals.next().unwrap_or_default();
+    let mut rest = text
+        .strip_prefix(prefix)
+        .ok_or_else(|| ParseError::new(expected(prefix)).at(first_char(text)))?;
+
+    let mut fields = vec![];
+    let mut literals = literals.peekable();
+    while let Some(literal) = literals.next() {
+        let end = if literal.is_empty() && literals.peek().is_none() {
+            rest.len()
+        } else {
+            rest.find(literal)
+                .ok_or_else(|| ParseError::new(expected(literal)).at(&rest[rest.len()..]))?
+        };
+        fields.push(&rest[..end]);
+        rest = &rest[end + literal.len()..];
+    }
+    if !rest.is_empty() {
+        return Err(ParseError::new(format!("unexpected `{}`", rest)).at(rest));
+    }
+    Ok(Fields { fields })
+}
@@ -0,0 +1,306 @@
+use std::fs;
+use std::io;
+
+use colored::*;
+use regex::Regex;
+
+use crate::cli_error::Error;
+use crate::runner::{example_file_path, fixture_dir};
+use crate::session::read_session;
+use crate::site::Site;
+
+/// The heading the second part of a puzzle starts with, once the first one is solved.
+const PART_TWO_HEADING: &str = "## --- Part Two ---";
+
+fn puzzle_dir(year: u16) -> String {
+    format!("puzzles/{}", year)
+}
+
+fn puzzle_file_path(year: u16, day_num: u8) -> String {
+    format!("{}/day{}.md", puzzle_dir(year), day_num)
+}
+
+fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+/// Escapes what Markdown would read as formatting in plain text.
+fn escape_markdown(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(c, '\\' | '*' | '`' | '[' | ']') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// The `<article class="day-desc">` sections of a puzzle page as Markdown, along with the
+/// contents of every `<pre><code>` block in them.
+///
+/// Only handles the handful of tags puzzle descriptions are written with, anything else is
+/// dropped and only its text is kept.
+pub fn to_markdown(page: &str, base_url: &str) -> (String, Vec<String>) {
+    let article_regex = Regex::new(r#"(?s)<article class="day-desc">(.*?)</article>"#).unwrap();
+    let tag_regex = Regex::new(r"(?s)<(/?)([a-zA-Z0-9]+)([^>]*)>").unwrap();
+    let href_regex = Regex::new(r#"href="([^"]*)""#).unwrap();
+    let whitespace_regex = Regex::new(r"\s+").unwrap();
+
+    let mut markdown = String::new();
+    let mut examples = Vec::new();
+    for article in article_regex.captures_iter(page) {
+        let article = &article[1];
+        let mut pre: Option<String> = None;
+        // Where the open inline code starts in `markdown`, and whether it's emphasized inside.
+        let mut code: Option<(usize, bool)> = None;
+        let mut links = Vec::new();
+        let mut last = 0;
+        for tag in tag_regex.captures_iter(article) {
+            let whole = tag.get(0).unwrap();
+            let text = decode_entities(&article[last..whole.start()]);
+            last = whole.end();
+            match &mut pre {
+                Some(block) => block.push_str(&text),
+                None if code.is_some() => markdown += &whitespace_regex.replace_all(&text, " "),
+                None => markdown += &escape_markdown(&whitespace_regex.replace_all(&text, " ")),
+            }
+
+            let closing = &tag[1] == "/";
+            match (tag[2].to_lowercase().as_str(), closing) {
+                ("pre", false) => pre = Some(String::new()),
+                ("pre", true) => {
+                    let block = pre.take().unwrap_or_default();
+                    let block = block.trim_end_matches('\n');
+                    markdown += &format!("\n```\n{}\n```\n\n", block);
+                    examples.push(block.to_string());
+                }
+                // Emphasis and code inside examples only highlight parts of them.
+                _ if pre.is_some() => {}
+                ("h2", false) => markdown += "## ",
+                ("h2", true) | ("p", true) => markdown += "\n\n",
+                ("li", false) => markdown += "- ",
+                ("li", true) => markdown += "\n",
+                ("ul", true) => markdown += "\n",
+                // Markdown can't emphasize inside code, so the whole code is emphasized instead.
+                ("em", _) if code.is_some() => {
+                    code = code.map(|(start, _)| (start, true));
+                }
+                ("em", _) => markdown += "**",
+                ("code", false) => {
+                    code = Some((markdown.len(), false));
+                    markdown += "`";
+                }
+                ("code", true) => match code.take() {
+                    Some((start, true)) => {
+                        markdown.insert_str(start, "**");
+                        markdown += "`**";
+                    }
+                    _ => markdown += "`",
+                },
+                ("br", _) => markdown += "\n",
+                ("a", false) => {
+                    let href = href_regex
+                        .captures(&tag[3])
+                        .map(|captures| decode_entities(&captures[1]))
+                        .unwrap_or_default();
+                    links.push(if href.starts_with('/') {
+                        format!("{}{}", base_url.trim_end_matches('/'), href)
+                    } else {
+                        href
+                    });
+                    markdown += "[";
+                }
+                ("a", true) => markdown += &format!("]({})", links.pop().unwrap_or_default()),
+                _ => {}
+            }
+        }
+        markdown += &escape_markdown(&decode_entities(&article[last..]));
+    }
+
+    // Whitespace between tags leaves spaces at the start of lines and more blank lines than
+    // needed, neither of which is inside a code block.
+    let mut tidy = String::new();
+    let mut in_code = false;
+    let mut blank = true;
+    for line in markdown.lines() {
+        if line.trim() == "```" {
+            in_code = !in_code;
+        }
+        let line = if in_code { line } else { line.trim() };
+        if line.is_empty() && !in_code {
+            if !blank {
+                tidy.push('\n');
+            }
+            blank = true;
+            continue;
+        }
+        tidy += line;
+        tidy.push('\n');
+        blank = false;
+    }
+    (tidy.trim_end().to_string() + "\n", examples)
+}
+
+/// Renders the inline formatting of one line of [`to_markdown`]'s Markdown for the terminal.
+fn render_inline(line: &str) -> String {
+    let mut rendered = String::new();
+    let mut text = String::new();
+    let mut emphasis = false;
+    let mut chars = line.chars().peekable();
+
+    let style = |text: &str, emphasis: bool| {
+        if emphasis {
+            text.bold().yellow().to_string()
+        } else {
+            text.to_string()
+        }
+    };
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => text.extend(chars.next()),
+            '*' if chars.peek() == Some(&'*') => {
+                chars.next();
+                rendered += &style(&text, emphasis);
+                text.clear();
+                emphasis = !emphasis;
+            }
+            '`' => {
+                rendered += &style(&text, emphasis);
+                text.clear();
+                let code = chars.by_ref().take_while(|&c| c != '`').collect::<String>();
+                // Code inside emphasis is highlighted even more on the site.
+                rendered += &if emphasis {
+                    code.bold().yellow().on_black().to_string()
+                } else {
+                    code.on_black().to_string()
+                };
+            }
+            '[' => {
+                rendered += &style(&text, emphasis);
+                text.clear();
+                let mut label = String::new();
+                while let Some(c) = chars.next() {
+                    match c {
+                        '\\' => label.extend(chars.next()),
+                        ']' => break,
+                        c => label.push(c),
+                    }
+                }
+                // The target in parentheses is dropped, the label is what's worth reading.
+                if chars.peek() == Some(&'(') {
+                    chars.by_ref().take_while(|&c| c != ')').for_each(drop);
+                }
+                rendered += &label.underline().to_string();
+            }
+            c => text.push(c),
+        }
+    }
+    rendered + &style(&text, emphasis)
+}
+
+/// Renders [`to_markdown`]'s Markdown for the terminal.
+pub fn render(markdown: &str) -> String {
+    let mut rendered = String::new();
+    let mut in_code = false;
+    for line in markdown.lines() {
+        if line == "```" {
+            in_code = !in_code;
+            continue;
+        }
+        if in_code {
+            rendered += &format!("    {}\n", line.dimmed());
+        } else if let Some(heading) = line.strip_prefix("## ") {
+            rendered += &format!("{}\n", heading.bold().green());
+        } else if let Some(item) = line.strip_prefix("- ") {
+            rendered += &format!("  • {}\n", render_inline(item));
+        } else {
+            rendered += &format!("{}\n", render_inline(line));
+        }
+    }
+    rendered
+}
+
+/// Writes `examples` next to the example fixture of `day_num` of `year` as `candidateN.txt`, and
+/// the first of them as the example itself if there's none yet. Returns the files it wrote.
+fn save_candidates(year: u16, day_num: u8, examples: &[String]) -> io::Result<Vec<String>> {
+    let dir = fixture_dir(year, day_num);
+    fs::create_dir_all(&dir)?;
+    let mut written = Vec::new();
+    for (i, example) in examples.iter().enumerate() {
+        let path = format!("{}/candidate{}.txt", dir, i + 1);
+        fs::write(&path, format!("{}\n", example))?;
+        written.push(path);
+    }
+
+    let example_path = example_file_path(year, day_num);
+    let has_example =
+        fs::read_to_string(&example_path).is_ok_and(|example| !example.trim().is_empty());
+    if let (false, Some(example)) = (has_example, examples.first()) {
+        fs::write(&example_path, format!("{}\n", example))?;
+        written.push(example_path);
+    }
+    Ok(written)
+}
+
+/// Prints the description of `day_num` of `year`, downloading it unless both parts are cached.
+///
+/// The examples in a freshly downloaded description become candidate fixtures.
+pub fn read_puzzle(year: u16, day_num: u8, site: &Site) -> Result<(), Error> {
+    if !(1..=25).contains(&day_num) {
+        return Err(Error::Day(advent_of_code::Error::InvalidDay(
+            day_num.to_string(),
+        )));
+    }
+    let puzzle_file_path = puzzle_file_path(year, day_num);
+    let cached = fs::read_to_string(&puzzle_file_path).ok();
+    let markdown = match cached {
+        // The second part only shows up once the first is solved, until then it's worth
+        // downloading the description again.
+        Some(markdown) if markdown.contains(PART_TWO_HEADING) => markdown,
+        _ => {
+            // Without a session only the first part is shown, which is still worth reading.
+            let page = match read_session() {
+                Ok(session) => site.puzzle_page(year, day_num, &session)?,
+                Err(_) => site.public_puzzle_page(year, day_num)?,
+            };
+            let (markdown, examples) = to_markdown(&page, site.base_url());
+            if markdown.trim().is_empty() {
+                return Err(Error::BadResponse {
+                    context: format!("Could not read the puzzle of {} day {}", year, day_num),
+                    message: "the page has no puzzle description, is the day open yet?".to_string(),
+                });
+            }
+
+            fs::create_dir_all(puzzle_dir(year))
+                .and_then(|_| fs::write(&puzzle_file_path, &markdown))
+                .map_err(|err| {
+                    Error::io(
+                        format!("Could not write puzzle file ({})", puzzle_file_path),
+                        err,
+                    )
+                })?;
+            let written = save_candidates(year, day_num, &examples).map_err(|err| {
+                Error::io(
+                    format!(
+                        "Could not write candidate fixtures ({})",
+                        fixture_dir(year, day_num)
+                    ),
+                    err,
+                )
+            })?;
+            for path in written {
+                eprintln!("{} {}", "Wrote".bold().green(), path);
+            }
+            markdown
+        }
+    };
+
+    print!("{}", render(&markdown));
+    Ok(())
+}
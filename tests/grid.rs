@@ -0,0 +1,65 @@
+//! The grids the days played out on a map share.
+
+use advent_of_code::grid::{Dir, Grid, Pos, SparseGrid};
+
+fn digits(input: &str) -> Grid<u32> {
+    Grid::parse(input, |_, c| Ok(c.to_digit(10).unwrap())).unwrap()
+}
+
+#[test]
+fn parses_maps_row_by_row() {
+    let grid = digits("123\n456\n");
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+    assert_eq!(grid[Pos::new(2, 0)], 3);
+    assert_eq!(grid[Pos::new(0, 1)], 4);
+    assert_eq!(grid.get(Pos::new(3, 0)), None);
+    assert_eq!(grid.get(Pos::new(0, -1)), None);
+    assert_eq!(grid.to_string(), "123\n456\n");
+}
+
+#[test]
+fn ragged_maps_are_rejected() {
+    let error = Grid::parse("123\n45\n", |_, c| Ok(c)).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "line 2 of the map is 2 wide, the first one is 3 wide"
+    );
+}
+
+#[test]
+fn neighbours_stay_inside_the_grid() {
+    let grid = digits("123\n456\n789\n");
+    let corner = grid.neighbours(Pos::new(0, 0)).collect::<Vec<_>>();
+    assert_eq!(corner, [Pos::new(1, 0), Pos::new(0, 1)]);
+    assert_eq!(grid.neighbours8(Pos::new(0, 0)).count(), 3);
+    assert_eq!(grid.neighbours8(Pos::new(1, 1)).count(), 8);
+}
+
+#[test]
+fn rays_go_to_the_edge() {
+    let grid = digits("123\n456\n789\n");
+    let from = Pos::new(1, 1);
+    assert_eq!(grid.ray(from, Dir::Up).collect::<Vec<_>>(), [&2]);
+    assert_eq!(grid.ray(from, Dir::Left).collect::<Vec<_>>(), [&4]);
+    assert_eq!(
+        grid.ray(Pos::new(0, 0), Dir::Right).collect::<Vec<_>>(),
+        [&2, &3]
+    );
+    assert_eq!(Dir::Up.turn_right(), Dir::Right);
+    assert_eq!(Dir::Left.opposite(), Dir::Right);
+}
+
+#[test]
+fn sparse_grids_draw_their_bounds() {
+    let grid = SparseGrid::parse("..#\n#..\n", |_, c| Ok((c == '#').then_some('#'))).unwrap();
+    assert_eq!(grid.len(), 2);
+    assert_eq!(grid.bounds(), Some((Pos::new(0, 0), Pos::new(2, 1))));
+    assert_eq!(grid.to_string(), "..#\n#..\n");
+
+    let moved = grid
+        .iter()
+        .map(|(pos, &c)| (pos + Pos::new(-5, 5), c))
+        .collect::<SparseGrid<_>>();
+    assert_eq!(moved.bounds(), Some((Pos::new(-5, 5), Pos::new(-3, 6))));
+    assert_eq!(SparseGrid::<char>::new().bounds(), None);
+}
@@ -0,0 +1,1152 @@
+//! Runs the CLI end-to-end against the bundled mock server instead of adventofcode.com.
+//!
+//! The mock server serves `tests/fixtures` (or a copy with different answers), so the downloaded
+//! inputs are the examples and the right answers are the example answers.
+
+use std::fs;
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command, Stdio};
+use std::sync::mpsc;
+use std::time::Duration;
+
+const BIN: &str = env!("CARGO_BIN_EXE_advent_of_code");
+
+struct MockSite {
+    child: Child,
+    url: String,
+}
+
+impl MockSite {
+    fn start(fixtures: &Path, cooldown: u64) -> Self {
+        Self::start_with_args(fixtures, &["--cooldown", &cooldown.to_string()])
+    }
+
+    fn start_with_args(fixtures: &Path, args: &[&str]) -> Self {
+        let mut child = Command::new(BIN)
+            .args(["mock-server", "--port", "0", "--fixtures"])
+            .arg(fixtures)
+            .args(args)
+            .stdout(Stdio::piped())
+            .spawn()
+            .expect("Could not start the mock server");
+        let mut line = String::new();
+        BufReader::new(child.stdout.as_mut().unwrap())
+            .read_line(&mut line)
+            .unwrap();
+        let url = line
+            .trim()
+            .strip_prefix("Listening on ")
+            .unwrap_or_else(|| panic!("Unexpected mock server output: {}", line))
+            .to_string();
+        Self { child, url }
+    }
+}
+
+impl Drop for MockSite {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+fn fixtures() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures")
+}
+
+/// An empty directory to run the CLI in, with a session but no inputs yet.
+fn workdir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("aoc-2022-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(dir.join("inputs")).unwrap();
+    fs::create_dir_all(dir.join("logs")).unwrap();
+    fs::write(dir.join("inputs/session.txt"), "mock-session").unwrap();
+    dir
+}
+
+/// Fixtures where day 1's part 1 answer is 1, lower than what day 1 answers.
+fn wrong_fixtures(name: &str) -> PathBuf {
+    let dir = workdir(&format!("{}-fixtures", name));
+    fs::create_dir_all(dir.join("2022/day1")).unwrap();
+    fs::copy(
+        fixtures().join("2022/day1/input.txt"),
+        dir.join("2022/day1/input.txt"),
+    )
+    .unwrap();
+    fs::write(dir.join("2022/day1/part1.txt"), "1").unwrap();
+    dir
+}
+
+/// The CLI running in `dir` against `site`, with no session but the one in `dir`.
+fn command(dir: &Path, site: &MockSite) -> Command {
+    let mut command = Command::new(BIN);
+    command
+        .current_dir(dir)
+        .env("AOC_BASE_URL", &site.url)
+        .env("XDG_CONFIG_HOME", dir.join("config"))
+        .env_remove("AOC_SESSION");
+    command
+}
+
+/// Runs `args` against `site` and returns the JSON record.
+fn run(dir: &Path, site: &MockSite, args: &[&str]) -> String {
+    let day = args[0];
+    let output = command(dir, site)
+        .args(args)
+        .args(["--format", "json"])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "Day {} failed: {}",
+        day,
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn downloads_input_and_submits_both_parts() {
+    let site = MockSite::start(&fixtures(), 0);
+    let dir = workdir("submit");
+
+    let record = run(&dir, &site, &["1"]);
+    assert!(record.contains(r#""stars":2"#), "{}", record);
+    assert!(record.contains(r#""part1_submit":"correct""#), "{}", record);
+    assert!(record.contains(r#""part2_submit":"correct""#), "{}", record);
+    assert_eq!(
+        fs::read_to_string(dir.join("inputs/2022/input1.txt")).unwrap(),
+        fs::read_to_string(fixtures().join("2022/day1/input.txt"))
+            .unwrap()
+            .trim_end()
+    );
+    let answers = fs::read_to_string(dir.join("answers/2022/day1.toml")).unwrap();
+    assert!(answers.contains(r#"part1 = "24000""#), "{}", answers);
+    assert!(answers.contains(r#"part2 = "45000""#), "{}", answers);
+
+    let record = run(&dir, &site, &["1"]);
+    assert!(record.contains(r#""stars":2"#), "{}", record);
+    assert!(
+        record.contains(r#""part1_submit":"not_submitted""#),
+        "{}",
+        record
+    );
+    assert!(
+        record.contains(r#""part2_submit":"not_submitted""#),
+        "{}",
+        record
+    );
+}
+
+#[test]
+fn wrong_answers_earn_no_stars() {
+    let site = MockSite::start(&wrong_fixtures("wrong"), 0);
+    let dir = workdir("wrong");
+
+    let record = run(&dir, &site, &["1"]);
+    assert!(record.contains(r#""stars":0"#), "{}", record);
+    assert!(
+        record.contains(r#""part1_submit":"too_high""#),
+        "{}",
+        record
+    );
+    assert!(
+        record.contains(r#""part2_submit":"not_submitted""#),
+        "{}",
+        record
+    );
+    assert!(!dir.join("answers/2022/day1.toml").exists());
+}
+
+#[test]
+fn refuses_to_submit_during_cooldown() {
+    let site = MockSite::start(&wrong_fixtures("cooldown"), 60);
+    let dir = workdir("cooldown");
+
+    let record = run(&dir, &site, &["1"]);
+    assert!(
+        record.contains(r#""part1_submit":"too_high""#),
+        "{}",
+        record
+    );
+    // As if the second answer came from another machine, which doesn't know it was rejected.
+    fs::remove_file(dir.join("logs/submissions.jsonl")).unwrap();
+    let record = run(&dir, &site, &["1"]);
+    assert!(
+        record.contains(r#""part1_submit":"cooldown""#),
+        "{}",
+        record
+    );
+    assert!(
+        record.contains(r#""part2_submit":"not_submitted""#),
+        "{}",
+        record
+    );
+}
+
+#[test]
+fn waits_out_the_cooldown() {
+    let site = MockSite::start(&wrong_fixtures("wait"), 1);
+    let dir = workdir("wait");
+
+    let record = run(&dir, &site, &["1"]);
+    assert!(
+        record.contains(r#""part1_submit":"too_high""#),
+        "{}",
+        record
+    );
+    // As if the second answer came from another machine, which doesn't know it was rejected.
+    fs::remove_file(dir.join("logs/submissions.jsonl")).unwrap();
+    let record = run(&dir, &site, &["1", "--wait"]);
+    assert!(
+        record.contains(r#""part1_submit":"too_high""#),
+        "{}",
+        record
+    );
+}
+
+#[test]
+fn stored_answers_are_not_resubmitted() {
+    let site = MockSite::start(&fixtures(), 0);
+    let dir = workdir("stored");
+    fs::create_dir_all(dir.join("answers/2022")).unwrap();
+    fs::write(dir.join("answers/2022/day1.toml"), "part1 = \"24000\"\n").unwrap();
+
+    // The mock server doesn't know part 1 was solved, so part 2 isn't open yet.
+    let record = run(&dir, &site, &["1"]);
+    assert!(
+        record.contains(r#""part1_submit":"not_submitted""#),
+        "{}",
+        record
+    );
+    assert!(
+        record.contains(r#""part2_submit":"wrong_level""#),
+        "{}",
+        record
+    );
+}
+
+#[test]
+fn rejected_answers_are_not_resubmitted() {
+    let site = MockSite::start(&wrong_fixtures("rejected"), 0);
+    let dir = workdir("rejected");
+
+    let record = run(&dir, &site, &["1"]);
+    assert!(
+        record.contains(r#""part1_submit":"too_high""#),
+        "{}",
+        record
+    );
+    let record = run(&dir, &site, &["1"]);
+    assert!(record.contains(r#""part1_submit":"refused""#), "{}", record);
+    let history = fs::read_to_string(dir.join("logs/submissions.jsonl")).unwrap();
+    assert_eq!(history.lines().count(), 1, "{}", history);
+    assert!(history.contains(r#""answer":"24000""#), "{}", history);
+}
+
+#[test]
+fn answers_out_of_bounds_are_not_submitted() {
+    let site = MockSite::start(&fixtures(), 0);
+    let dir = workdir("bounds");
+    fs::write(
+        dir.join("logs/submissions.jsonl"),
+        r#"{"day":1,"level":1,"answer":"20000","timestamp":0,"outcome":"too_high"}"#,
+    )
+    .unwrap();
+
+    let record = run(&dir, &site, &["1"]);
+    assert!(record.contains(r#""part1_submit":"refused""#), "{}", record);
+}
+
+#[test]
+fn session_check_detects_expired_sessions() {
+    let site = MockSite::start_with_args(&fixtures(), &["--session", "fresh"]);
+    let dir = workdir("session-check");
+
+    // inputs/session.txt holds an expired session.
+    let output = command(&dir, &site)
+        .args(["session", "check"])
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(4));
+
+    fs::create_dir_all(dir.join("config/aoc")).unwrap();
+    fs::write(dir.join("config/aoc/session"), "fresh\n").unwrap();
+    let output = command(&dir, &site)
+        .args(["session", "check"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("Mock user"), "{}", stdout);
+    assert!(stdout.contains("config/aoc/session"), "{}", stdout);
+
+    let output = command(&dir, &site)
+        .args(["session", "check"])
+        .env("AOC_SESSION", "stale")
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(4));
+}
+
+#[test]
+fn expired_sessions_fail_submissions_instead_of_being_wrong() {
+    let site = MockSite::start_with_args(&fixtures(), &["--session", "fresh"]);
+    let dir = workdir("expired-submit");
+    fs::create_dir_all(dir.join("inputs/2022")).unwrap();
+    fs::copy(
+        fixtures().join("2022/day1/input.txt"),
+        dir.join("inputs/2022/input1.txt"),
+    )
+    .unwrap();
+
+    let output = command(&dir, &site)
+        .args(["1", "--part", "1"])
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(4));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(!stdout.contains("Answer incorrect"), "{}", stdout);
+}
+
+#[test]
+fn logged_out_submissions_leave_the_history_alone() {
+    let site = MockSite::start_with_args(&fixtures(), &["--session", "fresh"]);
+    let dir = workdir("expired-history");
+    fs::create_dir_all(dir.join("inputs/2022")).unwrap();
+    fs::copy(
+        fixtures().join("2022/day1/input.txt"),
+        dir.join("inputs/2022/input1.txt"),
+    )
+    .unwrap();
+
+    let output = command(&dir, &site)
+        .args(["1", "--part", "1"])
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(4));
+    assert!(!dir.join("logs/submissions.jsonl").exists());
+
+    // Once logged in again, the answer is submitted like it never was.
+    fs::write(dir.join("inputs/session.txt"), "fresh").unwrap();
+    let record = run(&dir, &site, &["1", "--part", "1"]);
+    assert!(record.contains(r#""part1_submit":"correct""#), "{}", record);
+    let history = fs::read_to_string(dir.join("logs/submissions.jsonl")).unwrap();
+    assert_eq!(history.lines().count(), 1, "{}", history);
+}
+
+#[test]
+fn expired_sessions_are_caught_on_the_puzzle_page() {
+    let site = MockSite::start_with_args(&fixtures(), &["--session", "fresh"]);
+    let dir = workdir("expired-puzzle");
+    fs::create_dir_all(dir.join("inputs/2022")).unwrap();
+    fs::copy(
+        fixtures().join("2022/day1/input.txt"),
+        dir.join("inputs/2022/input1.txt"),
+    )
+    .unwrap();
+
+    // It can't tell which parts are solved, so it doesn't even solve them.
+    let output = command(&dir, &site).args(["1"]).output().unwrap();
+    assert_eq!(output.status.code(), Some(4));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(!stdout.contains("Part 1"), "{}", stdout);
+
+    // The description is public though.
+    fs::remove_file(dir.join("inputs/session.txt")).unwrap();
+    let output = command(&dir, &site).args(["read", "1"]).output().unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+#[test]
+fn login_pages_are_not_cached_as_input() {
+    let site = MockSite::start_with_args(&fixtures(), &["--session", "fresh"]);
+    let dir = workdir("login-page");
+
+    let output = command(&dir, &site).args(["1", "-s"]).output().unwrap();
+    assert_eq!(output.status.code(), Some(4));
+    assert!(!dir.join("inputs/2022/input1.txt").exists());
+}
+
+#[test]
+fn refetch_replaces_a_cached_login_page() {
+    let site = MockSite::start(&fixtures(), 0);
+    let dir = workdir("refetch");
+    fs::create_dir_all(dir.join("inputs/2022")).unwrap();
+    fs::write(
+        dir.join("inputs/2022/input1.txt"),
+        "Puzzle inputs differ by user.  Please log in to get your puzzle input.",
+    )
+    .unwrap();
+
+    let output = command(&dir, &site).args(["1", "-s"]).output().unwrap();
+    assert_eq!(output.status.code(), Some(3));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("refetch 1 --year 2022"), "{}", stderr);
+
+    let output = command(&dir, &site)
+        .args(["refetch", "1"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert_eq!(
+        fs::read_to_string(dir.join("inputs/2022/input1.txt")).unwrap(),
+        fs::read_to_string(fixtures().join("2022/day1/input.txt"))
+            .unwrap()
+            .trim_end()
+    );
+    run(&dir, &site, &["1", "-s"]);
+}
+
+#[test]
+fn new_scaffolds_a_day_without_touching_existing_ones() {
+    let fixtures = workdir("new-fixtures");
+    fs::create_dir_all(fixtures.join("2023/day1")).unwrap();
+    fs::write(fixtures.join("2023/day1/input.txt"), "1\n2\n3\n").unwrap();
+    let site = MockSite::start(&fixtures, 0);
+    let dir = workdir("new");
+    let sources = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("src/days");
+    fs::create_dir_all(dir.join("src/days")).unwrap();
+    fs::copy(sources.join("day.rs"), dir.join("src/days/day.rs")).unwrap();
+    fs::copy(sources.join("mod.rs"), dir.join("src/days/mod.rs")).unwrap();
+
+    let output = command(&dir, &site)
+        .args(["new", "1", "--year", "2023"])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(
+        fs::read_to_string(dir.join("src/days/y2023/day1.rs")).unwrap(),
+        fs::read_to_string(sources.join("day.rs")).unwrap()
+    );
+    let registry = fs::read_to_string(dir.join("src/days/mod.rs")).unwrap();
+    assert!(registry.contains("    2023 => [1],\n"), "{}", registry);
+    assert!(registry.contains("    2022 => [1, 2, 3,"), "{}", registry);
+    assert_eq!(
+        fs::read_to_string(dir.join("tests/fixtures/2023/day1/input.txt")).unwrap(),
+        ""
+    );
+    assert_eq!(
+        fs::read_to_string(dir.join("inputs/2023/input1.txt")).unwrap(),
+        "1\n2\n3"
+    );
+
+    fs::write(dir.join("src/days/y2023/day1.rs"), "// solved").unwrap();
+    let output = command(&dir, &site)
+        .args(["new", "1", "--year", "2023"])
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(3));
+    assert_eq!(
+        fs::read_to_string(dir.join("src/days/y2023/day1.rs")).unwrap(),
+        "// solved"
+    );
+    assert_eq!(
+        fs::read_to_string(dir.join("src/days/mod.rs")).unwrap(),
+        registry
+    );
+}
+
+#[test]
+fn read_caches_the_description_and_its_examples() {
+    let site = MockSite::start(&fixtures(), 0);
+    let dir = workdir("read");
+
+    let output = command(&dir, &site).args(["read", "1"]).output().unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("--- Day 1: Mock ---"), "{}", stdout);
+
+    let puzzle = fs::read_to_string(dir.join("puzzles/2022/day1.md")).unwrap();
+    assert!(puzzle.starts_with("## --- Day 1: Mock ---\n"), "{}", puzzle);
+    assert!(puzzle.contains("Solve **it**"), "{}", puzzle);
+    assert!(puzzle.contains("```\n1000\n2000\n3000\n"), "{}", puzzle);
+    let example = fs::read_to_string(fixtures().join("2022/day1/input.txt")).unwrap();
+    assert_eq!(
+        fs::read_to_string(dir.join("tests/fixtures/2022/day1/candidate1.txt")).unwrap(),
+        example
+    );
+    assert_eq!(
+        fs::read_to_string(dir.join("tests/fixtures/2022/day1/input.txt")).unwrap(),
+        example
+    );
+}
+
+#[test]
+fn custom_inputs_are_never_submitted() {
+    let site = MockSite::start(&fixtures(), 0);
+    let dir = workdir("custom-input");
+    fs::write(dir.join("example.txt"), "1\n\n2\n3").unwrap();
+
+    let record = run(&dir, &site, &["1", "--input", "example.txt"]);
+    assert!(record.contains(r#""part1_answer":"5""#), "{}", record);
+    assert!(record.contains(r#""part2_answer":"6""#), "{}", record);
+    assert!(
+        record.contains(r#""part1_submit":"not_submitted""#),
+        "{}",
+        record
+    );
+    assert!(!dir.join("inputs/2022/input1.txt").exists());
+    assert!(!dir.join("logs/submissions.jsonl").exists());
+
+    let mut child = command(&dir, &site)
+        .args(["1", "--input", "-", "--format", "json"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child.stdin.take().unwrap().write_all(b"4\n5\n\n6").unwrap();
+    let output = child.wait_with_output().unwrap();
+    let record = String::from_utf8_lossy(&output.stdout);
+    assert!(record.contains(r#""part1_answer":"9""#), "{}", record);
+
+    let dir = workdir("example-input");
+    fs::create_dir_all(dir.join("tests/fixtures/2022/day1")).unwrap();
+    fs::copy(
+        fixtures().join("2022/day1/input.txt"),
+        dir.join("tests/fixtures/2022/day1/input.txt"),
+    )
+    .unwrap();
+    let record = run(&dir, &site, &["1", "--example"]);
+    assert!(record.contains(r#""part1_answer":"24000""#), "{}", record);
+    assert!(
+        record.contains(r#""part1_submit":"not_submitted""#),
+        "{}",
+        record
+    );
+    assert!(!dir.join("logs/submissions.jsonl").exists());
+}
+
+#[test]
+fn trailing_newlines_are_trimmed_from_custom_inputs() {
+    let site = MockSite::start(&fixtures(), 0);
+    let dir = workdir("trailing-newline");
+    let example = format!(
+        "{}\r\n\n",
+        fs::read_to_string(fixtures().join("2022/day13/input.txt"))
+            .unwrap()
+            .trim_end()
+    );
+    fs::create_dir_all(dir.join("tests/fixtures/2022/day13")).unwrap();
+    fs::write(dir.join("tests/fixtures/2022/day13/input.txt"), &example).unwrap();
+    fs::write(dir.join("example.txt"), &example).unwrap();
+
+    for args in [&["13", "--example"][..], &["13", "--input", "example.txt"]] {
+        let record = run(&dir, &site, args);
+        assert!(record.contains(r#""part1_answer":"13""#), "{}", record);
+        assert!(record.contains(r#""part2_answer":"140""#), "{}", record);
+    }
+
+    let mut child = command(&dir, &site)
+        .args(["13", "--input", "-", "--format", "json"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(example.as_bytes())
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+    assert!(output.status.success());
+    let record = String::from_utf8_lossy(&output.stdout);
+    assert!(record.contains(r#""part1_answer":"13""#), "{}", record);
+}
+
+#[test]
+fn only_the_chosen_part_is_submitted() {
+    let site = MockSite::start(&fixtures(), 0);
+    let dir = workdir("part");
+
+    // Part 2 isn't open before part 1 is solved.
+    let record = run(&dir, &site, &["1", "--part", "2"]);
+    assert!(record.contains(r#""part1_answer":"""#), "{}", record);
+    assert!(
+        record.contains(r#""part2_submit":"not_submitted""#),
+        "{}",
+        record
+    );
+
+    let record = run(&dir, &site, &["1", "--part", "1"]);
+    assert!(record.contains(r#""part1_submit":"correct""#), "{}", record);
+    assert!(record.contains(r#""part2_answer":"""#), "{}", record);
+    assert!(record.contains(r#""stars":1"#), "{}", record);
+
+    let record = run(&dir, &site, &["1", "--part", "2"]);
+    assert!(record.contains(r#""part2_submit":"correct""#), "{}", record);
+    assert!(record.contains(r#""stars":2"#), "{}", record);
+
+    let record = run(&dir, &site, &["1", "--parse-only"]);
+    assert!(record.contains(r#""part1_answer":"""#), "{}", record);
+    assert!(record.contains(r#""part2_answer":"""#), "{}", record);
+}
+
+#[test]
+fn memory_is_only_reported_with_mem() {
+    let site = MockSite::start(&fixtures(), 0);
+    let dir = workdir("mem");
+    fs::write(dir.join("example.txt"), "1\n\n2\n3").unwrap();
+
+    let record = run(&dir, &site, &["1", "--input", "example.txt"]);
+    assert!(!record.contains("_mem"), "{}", record);
+
+    let record = run(
+        &dir,
+        &site,
+        &["1", "--input", "example.txt", "--mem", "--part", "1"],
+    );
+    assert!(
+        record.contains(r#""parse_mem":{"peak_bytes":"#),
+        "{}",
+        record
+    );
+    assert!(
+        record.contains(r#""part1_mem":{"peak_bytes":"#),
+        "{}",
+        record
+    );
+    assert!(!record.contains("part2_mem"), "{}", record);
+
+    let output = command(&dir, &site)
+        .args(["1", "--input", "example.txt", "--mem"])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("Parsing Memory:"), "{}", stdout);
+    assert!(stdout.contains("Part 2 Memory:"), "{}", stdout);
+}
+
+#[test]
+fn all_goes_on_after_panics_and_timeouts() {
+    let site = MockSite::start(&fixtures(), 0);
+    let dir = workdir("crash");
+    fs::create_dir_all(dir.join("inputs/2022")).unwrap();
+    // Day 22's cube net is hard-coded for the real input, its part 2 panics on the example.
+    fs::copy(
+        fixtures().join("2022/day22/input.txt"),
+        dir.join("inputs/2022/input22.txt"),
+    )
+    .unwrap();
+    fs::copy(
+        fixtures().join("2022/day25/input.txt"),
+        dir.join("inputs/2022/input25.txt"),
+    )
+    .unwrap();
+
+    let output = command(&dir, &site)
+        .args(["all", "-s", "-i", "--format", "json"])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains(r#""day":25"#), "{}", stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let summary = &stderr[stderr.find("Days that didn't finish:").unwrap()..];
+    assert!(summary.contains("Day 22 part 2 panicked"), "{}", summary);
+    assert_eq!(summary.lines().count(), 2, "{}", summary);
+
+    // Nothing finishes in no time at all.
+    let output = command(&dir, &site)
+        .args(["25", "-s", "-i", "--timeout", "0"])
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(8));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Day 25 timed out"), "{}", stderr);
+}
+
+/// Runs `verify` on `day` in `dir`, returning its exit code and the summary line.
+fn verify(dir: &Path, site: &MockSite, day: &str) -> (Option<i32>, String) {
+    let output = command(dir, site).args(["verify", day]).output().unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let summary = stdout
+        .lines()
+        .find(|line| line.starts_with("Verified:"))
+        .unwrap_or_else(|| panic!("No summary in: {}", stdout))
+        .to_string();
+    (output.status.code(), summary)
+}
+
+#[test]
+fn verify_checks_the_cached_input_against_the_stored_answers() {
+    let site = MockSite::start(&fixtures(), 0);
+    let dir = workdir("verify");
+    fs::create_dir_all(dir.join("inputs/2022")).unwrap();
+    fs::copy(
+        fixtures().join("2022/day1/input.txt"),
+        dir.join("inputs/2022/input1.txt"),
+    )
+    .unwrap();
+    fs::create_dir_all(dir.join("answers/2022")).unwrap();
+    let part1 = fs::read_to_string(fixtures().join("2022/day1/part1.txt")).unwrap();
+    let part2 = fs::read_to_string(fixtures().join("2022/day1/part2.txt")).unwrap();
+
+    fs::write(
+        dir.join("answers/2022/day1.toml"),
+        format!("part1 = {:?}\n", part1.trim()),
+    )
+    .unwrap();
+    assert_eq!(
+        verify(&dir, &site, "1"),
+        (
+            Some(0),
+            "Verified: 1 passed, 0 failed, 1 unchecked".to_string()
+        )
+    );
+
+    fs::write(
+        dir.join("answers/2022/day1.toml"),
+        format!("part1 = {:?}\npart2 = {:?}\n", part1.trim(), part2.trim()),
+    )
+    .unwrap();
+    assert_eq!(
+        verify(&dir, &site, "1"),
+        (
+            Some(0),
+            "Verified: 2 passed, 0 failed, 0 unchecked".to_string()
+        )
+    );
+
+    fs::write(
+        dir.join("answers/2022/day1.toml"),
+        format!("part1 = {:?}\npart2 = \"1\"\n", part1.trim()),
+    )
+    .unwrap();
+    assert_eq!(
+        verify(&dir, &site, "1"),
+        (
+            Some(1),
+            "Verified: 1 passed, 1 failed, 0 unchecked".to_string()
+        )
+    );
+}
+
+#[test]
+fn verify_all_counts_days_without_an_input_as_unchecked() {
+    let site = MockSite::start(&fixtures(), 0);
+    let dir = workdir("verify-all");
+    fs::create_dir_all(dir.join("inputs/2022")).unwrap();
+    fs::copy(
+        fixtures().join("2022/day1/input.txt"),
+        dir.join("inputs/2022/input1.txt"),
+    )
+    .unwrap();
+    fs::create_dir_all(dir.join("answers/2022")).unwrap();
+    fs::write(dir.join("answers/2022/day1.toml"), "part1 = \"1\"\n").unwrap();
+
+    assert_eq!(
+        verify(&dir, &site, "all"),
+        (
+            Some(1),
+            "Verified: 0 passed, 1 failed, 49 unchecked".to_string()
+        )
+    );
+
+    fs::remove_file(dir.join("answers/2022/day1.toml")).unwrap();
+    assert_eq!(
+        verify(&dir, &site, "all"),
+        (
+            Some(0),
+            "Verified: 0 passed, 0 failed, 50 unchecked".to_string()
+        )
+    );
+}
+
+#[test]
+fn parallel_days_print_in_order_without_interleaving() {
+    let site = MockSite::start(&fixtures(), 0);
+    let dir = workdir("jobs");
+    fs::create_dir_all(dir.join("inputs/2022")).unwrap();
+    for day in 1..=25 {
+        fs::copy(
+            fixtures().join(format!("2022/day{}/input.txt", day)),
+            dir.join(format!("inputs/2022/input{}.txt", day)),
+        )
+        .unwrap();
+    }
+
+    let stdout = |args: &[&str]| {
+        let output = command(&dir, &site).args(args).output().unwrap();
+        String::from_utf8(output.stdout).unwrap()
+    };
+    let sequential = stdout(&["all", "-s", "-i"]);
+    let days = sequential
+        .lines()
+        .filter_map(|line| line.strip_prefix("Day "))
+        .map(|day| day.parse::<u8>().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(days, (1..=25).collect::<Vec<_>>(), "{}", sequential);
+    // Each day's lines stay together under its header, exactly as when run one at a time.
+    assert_eq!(stdout(&["all", "-s", "-i", "-j", "8"]), sequential);
+}
+
+#[test]
+fn csv_quotes_answers_that_need_it() {
+    let site = MockSite::start(&fixtures(), 0);
+    let dir = workdir("csv");
+    let csv = |args: &[&str]| {
+        let output = command(&dir, &site)
+            .args(args)
+            .args(["--format", "csv"])
+            .output()
+            .unwrap();
+        String::from_utf8(output.stdout).unwrap()
+    };
+
+    // The top crates spell `",`.
+    fs::write(
+        dir.join("crates.txt"),
+        "[\"] [,]\n 1   2 \n\nmove 1 from 1 to 1\n",
+    )
+    .unwrap();
+    let stdout = csv(&["5", "--input", "crates.txt"]);
+    let record = stdout.lines().nth(1).unwrap();
+    assert!(
+        record.starts_with(r#"2022,5,0,""",",not_submitted,""",",not_submitted,"#),
+        "{}",
+        stdout
+    );
+
+    let input = fixtures().join("2022/day10/input.txt");
+    let stdout = csv(&["10", "--input", input.to_str().unwrap()]);
+    let record = &stdout[stdout.find('\n').unwrap() + 1..];
+    assert!(
+        record.starts_with("2022,10,0,13140,not_submitted,\""),
+        "{}",
+        stdout
+    );
+    // The six rows of the CRT stay inside the quoted field of a single record.
+    assert_eq!(record.lines().count(), 6, "{}", stdout);
+    assert!(record.trim_end().ends_with(','), "{}", stdout);
+}
+
+#[test]
+fn days_that_fail_still_get_a_record() {
+    let site = MockSite::start(&fixtures(), 0);
+    let dir = workdir("failed-records");
+    fs::create_dir_all(dir.join("inputs/2022")).unwrap();
+    for day in [22, 25] {
+        fs::copy(
+            fixtures().join(format!("2022/day{}/input.txt", day)),
+            dir.join(format!("inputs/2022/input{}.txt", day)),
+        )
+        .unwrap();
+    }
+    let output = |args: &[&str]| {
+        let output = command(&dir, &site).args(args).output().unwrap();
+        (
+            output.status.code(),
+            String::from_utf8(output.stdout).unwrap(),
+        )
+    };
+
+    let (code, stdout) = output(&["22", "-s", "-i", "--format", "json"]);
+    assert_eq!(code, Some(7));
+    assert!(stdout.contains(r#""day":22,"stars":0"#), "{}", stdout);
+    assert!(
+        stdout.contains(r#""error":"Day 22 part 2 panicked"#),
+        "{}",
+        stdout
+    );
+
+    let (code, stdout) = output(&["25", "-s", "-i", "--timeout", "0", "--format", "json"]);
+    assert_eq!(code, Some(8));
+    assert!(
+        stdout.contains(r#""error":"Day 25 timed out"#),
+        "{}",
+        stdout
+    );
+
+    fs::write(dir.join("bad.txt"), "1\n\nQ\n").unwrap();
+    let (code, stdout) = output(&["1", "--input", "bad.txt", "--format", "csv"]);
+    assert_eq!(code, Some(6));
+    assert!(
+        stdout.contains(
+            "2022,1,0,,not_submitted,,not_submitted,0,0,0,\"Could not parse input of day 1: line 3"
+        ),
+        "{}",
+        stdout
+    );
+
+    // Successful days have no error, in JSON the field is left out.
+    let (_, stdout) = output(&["all", "-s", "-i", "--format", "csv"]);
+    assert!(stdout.starts_with("year,day,stars,"), "{}", stdout);
+    assert!(stdout.contains("\n2022,22,0,"), "{}", stdout);
+    assert!(!run(&dir, &site, &["25", "-s", "-i"]).contains("error"));
+}
+
+/// A directory with day 1's example as its cached input, to benchmark.
+fn bench_workdir(name: &str) -> PathBuf {
+    let dir = workdir(name);
+    fs::create_dir_all(dir.join("inputs/2022")).unwrap();
+    fs::copy(
+        fixtures().join("2022/day1/input.txt"),
+        dir.join("inputs/2022/input1.txt"),
+    )
+    .unwrap();
+    dir
+}
+
+/// The mean, deviation, median, min and max of the phase `name` in the output of `bench`, as
+/// printed.
+fn bench_stats<'a>(stdout: &'a str, name: &str) -> [&'a str; 5] {
+    let line = stdout
+        .lines()
+        .find_map(|line| line.strip_prefix(name))
+        .unwrap_or_else(|| panic!("No {} in: {}", name, stdout));
+    let line = line.split(" (").next().unwrap();
+    let (mean, rest) = line.trim().split_once(" ± ").unwrap();
+    let (stddev, rest) = rest.split_once(", median ").unwrap();
+    let (median, rest) = rest.split_once(", min ").unwrap();
+    let (min, max) = rest.split_once(", max ").unwrap();
+    [mean, stddev, median, min, max]
+}
+
+#[test]
+fn bench_summarises_the_measured_iterations() {
+    let site = MockSite::start(&fixtures(), 0);
+    let dir = bench_workdir("bench-stats");
+    let bench = |iterations: &str| {
+        let output = command(&dir, &site)
+            .args(["bench", "1", "-w", "0", "-n", iterations])
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+        String::from_utf8(output.stdout).unwrap()
+    };
+
+    let stdout = bench("1");
+    for name in ["Parsing:", "Part 1:", "Part 2:"] {
+        let [mean, stddev, median, min, max] = bench_stats(&stdout, name);
+        assert_eq!(stddev, "0 ns", "{}", stdout);
+        assert!(mean == median && median == min && min == max, "{}", stdout);
+    }
+
+    // The median of two samples is their mean.
+    let stdout = bench("2");
+    for name in ["Parsing:", "Part 1:", "Part 2:"] {
+        let [mean, _, median, _, _] = bench_stats(&stdout, name);
+        assert_eq!(mean, median, "{}", stdout);
+    }
+}
+
+#[test]
+fn bench_keeps_the_first_run_as_baseline() {
+    let site = MockSite::start(&fixtures(), 0);
+    let dir = bench_workdir("bench-baseline");
+    let baseline_file = dir.join("benchmarks/baseline.toml");
+    let bench = |args: &[&str]| {
+        let status = command(&dir, &site)
+            .args(["bench", "1", "-w", "0", "-n", "2"])
+            .args(args)
+            .status()
+            .unwrap();
+        assert!(status.success());
+    };
+
+    bench(&[]);
+    let baseline = fs::read_to_string(&baseline_file).unwrap();
+    assert!(baseline.starts_with("[2022.day1]\n"), "{}", baseline);
+    for key in ["parsing", "part1", "part2"] {
+        assert!(baseline.contains(&format!("\n{} = ", key)), "{}", baseline);
+    }
+
+    let saved = "[2022.day1]\nparsing = 1\npart1 = 2\npart2 = 3\n";
+    fs::write(&baseline_file, saved).unwrap();
+    bench(&[]);
+    assert_eq!(fs::read_to_string(&baseline_file).unwrap(), saved);
+
+    bench(&["-b"]);
+    assert_ne!(fs::read_to_string(&baseline_file).unwrap(), saved);
+
+    fs::write(&baseline_file, "not toml").unwrap();
+    let output = command(&dir, &site).args(["bench", "1"]).output().unwrap();
+    assert_eq!(output.status.code(), Some(3));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Could not parse baseline file"),
+        "{}",
+        stderr
+    );
+}
+
+#[test]
+fn bench_flags_regressions_and_improvements_against_the_baseline() {
+    let site = MockSite::start(&fixtures(), 0);
+    let dir = bench_workdir("bench-regression");
+    fs::create_dir_all(dir.join("benchmarks")).unwrap();
+    let bench = |parsing: u64| {
+        let baseline = format!("[2022.day1]\nparsing = {}\npart1 = 0\npart2 = 0\n", parsing);
+        fs::write(dir.join("benchmarks/baseline.toml"), baseline).unwrap();
+        let output = command(&dir, &site)
+            .args(["bench", "1", "-w", "0", "-n", "2"])
+            .env("CLICOLOR_FORCE", "1")
+            .output()
+            .unwrap();
+        let stdout = String::from_utf8(output.stdout).unwrap();
+        stdout
+            .lines()
+            .find(|line| line.contains("Parsing:"))
+            .unwrap()
+            .to_string()
+    };
+
+    // Parsing takes far longer than a nanosecond, and far less than a minute.
+    let parsing = bench(1);
+    assert!(parsing.contains("(\u{1b}[31m+"), "{:?}", parsing);
+    let parsing = bench(60_000_000_000);
+    assert!(parsing.contains("(\u{1b}[32m-"), "{:?}", parsing);
+}
+
+/// A running `watch`, stopped when dropped, whose stdout is read line by line.
+struct Watch {
+    child: Child,
+    lines: mpsc::Receiver<String>,
+}
+
+impl Watch {
+    /// Reads lines until a whole run is printed, the one of the input coming last.
+    fn next_run(&self) -> Vec<String> {
+        let mut lines = Vec::new();
+        let mut on_input = false;
+        loop {
+            let line = self
+                .lines
+                .recv_timeout(Duration::from_secs(30))
+                .unwrap_or_else(|_| panic!("No complete run in: {:?}", lines));
+            on_input |= line == "Input";
+            let done = on_input && line.starts_with("Part 2:");
+            lines.push(line);
+            if done {
+                return lines;
+            }
+        }
+    }
+}
+
+impl Drop for Watch {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+#[cfg(unix)]
+#[test]
+fn watch_reruns_once_per_burst_of_changes_and_diffs_the_answers() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let site = MockSite::start(&fixtures(), 0);
+    let dir = workdir("watch");
+    fs::create_dir_all(dir.join("src/days/y2022")).unwrap();
+    fs::write(dir.join("src/days/y2022/day1.rs"), "").unwrap();
+    fs::create_dir_all(dir.join("inputs/2022")).unwrap();
+    fs::copy(
+        fixtures().join("2022/day1/input.txt"),
+        dir.join("inputs/2022/input1.txt"),
+    )
+    .unwrap();
+    fs::create_dir_all(dir.join("tests/fixtures/2022/day1")).unwrap();
+    for file in ["input.txt", "part1.txt", "part2.txt"] {
+        fs::copy(
+            fixtures().join("2022/day1").join(file),
+            dir.join("tests/fixtures/2022/day1").join(file),
+        )
+        .unwrap();
+    }
+    // Stands in for `cargo run`, logging every run and running this build instead.
+    fs::create_dir_all(dir.join("bin")).unwrap();
+    let cargo = dir.join("bin/cargo");
+    fs::write(
+        &cargo,
+        format!(
+            "#!/bin/sh\necho \"$@\" >> runs.log\nwhile [ \"$1\" != -- ]; do shift; done\nshift\nexec {} \"$@\"\n",
+            BIN
+        ),
+    )
+    .unwrap();
+    fs::set_permissions(&cargo, fs::Permissions::from_mode(0o755)).unwrap();
+    let runs = || {
+        fs::read_to_string(dir.join("runs.log"))
+            .unwrap()
+            .lines()
+            .count()
+    };
+
+    let path = format!(
+        "{}:{}",
+        dir.join("bin").display(),
+        std::env::var("PATH").unwrap()
+    );
+    let mut child = command(&dir, &site)
+        .args(["watch", "1"])
+        .env("PATH", path)
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    let stdout = BufReader::new(child.stdout.take().unwrap());
+    let (sender, receiver) = mpsc::channel();
+    std::thread::spawn(move || {
+        for line in stdout.lines() {
+            if sender.send(line.unwrap()).is_err() {
+                break;
+            }
+        }
+    });
+    let watch = Watch {
+        child,
+        lines: receiver,
+    };
+
+    let first = watch.next_run();
+    assert!(first.contains(&"Part 1: 24000".to_string()), "{:?}", first);
+    assert_eq!(runs(), 2);
+
+    // An editor saving in several writes, over a bit less than the time `watch` waits for, with
+    // its next check for changes falling in the middle of them.
+    std::thread::sleep(Duration::from_millis(150));
+    for input in ["1", "1\n\n2", "1\n\n2\n", "1\n\n2\n3"] {
+        fs::write(dir.join("inputs/2022/input1.txt"), input).unwrap();
+        std::thread::sleep(Duration::from_millis(80));
+    }
+    let second = watch.next_run();
+    let input = second.iter().position(|line| line == "Input").unwrap();
+    assert!(
+        second[..input]
+            .iter()
+            .filter(|line| line.starts_with("Part"))
+            .all(|line| line.ends_with("(unchanged)")),
+        "{:?}",
+        second
+    );
+    assert!(
+        second[input..].contains(&"Part 1: 5 (changed)".to_string()),
+        "{:?}",
+        second
+    );
+    assert!(
+        second[input..]
+            .iter()
+            .any(|line| line.trim() == "was 24000"),
+        "{:?}",
+        second
+    );
+
+    std::thread::sleep(Duration::from_secs(1));
+    assert_eq!(runs(), 4);
+    let rest = watch.lines.try_iter().collect::<Vec<_>>();
+    assert!(!rest.contains(&"Day 1".to_string()), "{:?}", rest);
+}
@@ -0,0 +1,58 @@
+//! Reads letters drawn like the puzzles draw them.
+
+use advent_of_code::ocr::read_letters;
+
+#[test]
+fn reads_the_small_font_with_blank_rows_around_it() {
+    let rows = [
+        "",
+        ".##..###..####.#..#.#....",
+        "#..#.#..#.#....#.#..#....",
+        "#..#.#..#.###..##...#....",
+        "####.###..#....#.#..#....",
+        "#..#.#....#....#.#..#....",
+        "#..#.#....####.#..#.####.",
+        "",
+    ];
+    assert_eq!(read_letters(&rows, '#').as_deref(), Some("APEKL"));
+}
+
+#[test]
+fn reads_the_large_font() {
+    let rows = [
+        "#....#..######",
+        "##...#.......#",
+        "##...#.......#",
+        "#.#..#......#.",
+        "#.#..#.....#..",
+        "#..#.#....#...",
+        "#..#.#...#....",
+        "#...##..#.....",
+        "#...##..#.....",
+        "#....#..######",
+    ];
+    assert_eq!(read_letters(&rows, '#').as_deref(), Some("NZ"));
+}
+
+#[test]
+fn reads_day_10_crt_pixels() {
+    let rows = [
+        "###  #  # ",
+        "#  # #  # ",
+        "#  # #### ",
+        "###  #  # ",
+        "#    #  # ",
+        "#    #  # ",
+    ];
+    assert_eq!(read_letters(&rows, '#').as_deref(), Some("PH"));
+}
+
+#[test]
+fn unknown_drawings_are_not_read() {
+    // Neither font is 3 rows high.
+    assert_eq!(read_letters(&["#", "#", "#"], '#'), None);
+    // `M` never showed up in a puzzle.
+    let rows = ["#...#", "##.##", "#.#.#", "#...#", "#...#", "#...#"];
+    assert_eq!(read_letters(&rows, '#'), None);
+    assert_eq!(read_letters::<&str>(&[], '#'), None);
+}
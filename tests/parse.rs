@@ -0,0 +1,158 @@
+//! The parsing helpers, and the diagnostics they give for malformed input.
+
+use advent_of_code::days::{parse_input, y2022};
+use advent_of_code::parse::{blocks, integers, integers_n, template};
+use advent_of_code::{Error, ParseError};
+
+#[test]
+fn finds_integers_and_their_signs() {
+    assert_eq!(
+        integers::<i64>("Sensor at x=-2, y=18: 2-4").unwrap(),
+        [-2, 18, 2, 4]
+    );
+    assert_eq!(integers::<u8>("no numbers").unwrap(), []);
+    assert_eq!(integers_n::<3, u32>("1,2,3").unwrap(), [1, 2, 3]);
+    assert_eq!(
+        integers_n::<3, u32>("1,2").unwrap_err().message(),
+        "expected 3 numbers separated by `,`, found 2"
+    );
+    assert_eq!(
+        integers_n::<2, u32>("Q03,4").unwrap_err().message(),
+        "invalid value `Q03`: invalid digit found in string"
+    );
+    assert_eq!(
+        integers_n::<2, u32>("4 9").unwrap_err().message(),
+        "expected 2 numbers separated by `,`, found 1"
+    );
+}
+
+#[test]
+fn splits_blocks_on_empty_lines() {
+    let input = "1\n2\n\n3\n";
+    assert_eq!(blocks(input).collect::<Vec<_>>(), ["1\n2", "3"]);
+}
+
+#[test]
+fn templates_match_fields_between_the_literal_text() {
+    let fields = template("move {} from {} to {}", "move 10 from 1 to 3").unwrap();
+    assert_eq!(fields.len(), 3);
+    assert_eq!(fields.parse::<u32>(0).unwrap(), 10);
+    assert_eq!(fields.str(2), "3");
+
+    let error = template("move {} from {} to {}", "move 10 to 3").unwrap_err();
+    assert_eq!(error.message(), "expected `from`");
+    let error = template("Monkey {}:", "Monkey 0: hi").unwrap_err();
+    assert_eq!(error.message(), "unexpected ` hi`");
+}
+
+#[test]
+fn errors_point_at_the_line_and_column_of_the_input() {
+    let input = "move 1 from 2 to 1\nmove x from 1 to 2\n";
+    let line = input.lines().nth(1).unwrap();
+    let error = template("move {} from {} to {}", line)
+        .and_then(|fields| fields.parse::<u32>(0))
+        .unwrap_err()
+        .locate(input);
+    let location = error.location().unwrap();
+    assert_eq!((location.line, location.column), (2, 6));
+    assert_eq!(
+        error.to_string(),
+        "line 2, column 6: invalid value `x`: invalid digit found in string\n    \
+         move x from 1 to 2\n         ^"
+    );
+
+    // Errors about copies of the input can't be located.
+    let copy = line.to_string();
+    let error = ParseError::new("bad").at(&copy).locate(input);
+    assert_eq!(error.location(), None);
+    assert_eq!(error.to_string(), "bad");
+}
+
+#[test]
+fn malformed_days_get_a_diagnostic_instead_of_a_panic() {
+    let input = "    [D]\n[N] [C]\n 1   2\n\nmove 1 from 2 to 1\nmove 1 from 3 to 1\n";
+    let Err(Error::Parse { day, source }) = parse_input::<y2022::day5::Day>(5, input) else {
+        panic!("day 5 accepted a move from a stack that doesn't exist");
+    };
+    assert_eq!(day, 5);
+    assert_eq!(source.message(), "there is no stack 3");
+    let location = source.location().unwrap();
+    assert_eq!((location.line, location.column), (6, 13));
+
+    let input = "Sensor at x=2, y=18: closest beacon at x=-2, y=15\n";
+    let Err(Error::Parse { source, .. }) = parse_input::<y2022::day15::Day>(15, input) else {
+        panic!("day 15 accepted a malformed sensor");
+    };
+    assert_eq!(source.location().unwrap().line, 1);
+
+    let input = "498,4 -> 498,6\n503,4 -> Q502,4\n";
+    let Err(Error::Parse { source, .. }) = parse_input::<y2022::day14::Day>(14, input) else {
+        panic!("day 14 accepted a corrupted position");
+    };
+    let location = source.location().unwrap();
+    assert_eq!((location.line, location.column), (2, 10));
+
+    for (input, column) in [("498,4 -> 4 9\n", 10), ("498,4 -> 498,6 -> 500,8\n", 10)] {
+        let Err(Error::Parse { source, .. }) = parse_input::<y2022::day14::Day>(14, input) else {
+            panic!("day 14 accepted the path `{}`", input.trim_end());
+        };
+        let location = source.location().unwrap();
+        assert_eq!((location.line, location.column), (1, column));
+    }
+
+    let input = "Monkey 0:\n  Starting items: 79, Q98\n";
+    let Err(Error::Parse { source, .. }) = parse_input::<y2022::day11::Day>(11, input) else {
+        panic!("day 11 accepted a corrupted item");
+    };
+    assert_eq!(source.location().unwrap().line, 2);
+}
+
+#[test]
+fn bad_characters_are_located() {
+    fn location<D: advent_of_code::Day>(day: u8, input: &str) -> (usize, usize) {
+        let Err(Error::Parse { source, .. }) = parse_input::<D>(day, input) else {
+            panic!("day {} accepted {:?}", day, input);
+        };
+        let location = source.location().unwrap();
+        (location.line, location.column)
+    }
+
+    assert_eq!(location::<y2022::day8::Day>(8, "30373\n25Q12"), (2, 3));
+    assert_eq!(location::<y2022::day12::Day>(12, "SabE\nab?c"), (2, 3));
+    assert_eq!(location::<y2022::day13::Day>(13, "[1,1]\n[1,Q]"), (2, 4));
+    assert_eq!(location::<y2022::day17::Day>(17, ">>Q<"), (1, 3));
+    assert_eq!(location::<y2022::day22::Day>(22, "..\n.Q\n\n10R5"), (2, 2));
+    assert_eq!(location::<y2022::day23::Day>(23, "..#\n.Q."), (2, 2));
+    assert_eq!(
+        location::<y2022::day24::Day>(24, "#.###\n#>Q.#\n###.#"),
+        (2, 3)
+    );
+    assert_eq!(location::<y2022::day25::Day>(25, "1=-0-2\n12Q"), (2, 3));
+}
+
+#[test]
+fn inputs_the_solutions_would_panic_on_are_rejected() {
+    fn line<D: advent_of_code::Day>(day: u8, input: &str) -> usize {
+        let Err(Error::Parse { source, .. }) = parse_input::<D>(day, input) else {
+            panic!("day {} accepted {:?}", day, input);
+        };
+        source.location().unwrap().line
+    }
+
+    assert_eq!(line::<y2022::day2::Day>(2, "A Y\nA Y garbage"), 2);
+    assert_eq!(line::<y2022::day3::Day>(3, "garbage"), 1);
+    assert_eq!(line::<y2022::day3::Day>(3, "abab\ncdcd"), 2);
+    assert_eq!(line::<y2022::day6::Day>(6, "abc"), 1);
+    assert_eq!(line::<y2022::day7::Day>(7, ""), 1);
+    assert_eq!(line::<y2022::day7::Day>(7, "$ cd /\n$ ls\ndir a"), 3);
+    assert_eq!(line::<y2022::day15::Day>(15, ""), 1);
+    assert_eq!(line::<y2022::day17::Day>(17, ""), 1);
+    assert_eq!(
+        line::<y2022::day21::Day>(21, "root: humn + pppw\nhumn: 5"),
+        1
+    );
+    assert_eq!(
+        line::<y2022::day24::Day>(24, "#.###\n#>..#\n#..>..#\n###.#"),
+        3
+    );
+}